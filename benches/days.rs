@@ -0,0 +1,50 @@
+use divan::Bencher;
+use adventofcode::{find_puzzle, puzzles, read_year_input, year_path};
+use adventofcode::runner::PuzzleId;
+use adventofcode::solution::{Part, Puzzle};
+
+fn main() {
+    divan::main();
+}
+
+/// Every registered day with a puzzle input, as `year/day`
+fn days() -> Vec<String> {
+    puzzles()
+        .filter(|d| year_path(d.year(), &input_file(d.day())).is_ok_and(|path| path.exists()))
+        .map(|d| PuzzleId { year: d.year(), day: d.day() }.to_string())
+        .collect()
+}
+
+fn input_file(day: u8) -> String {
+    format!("day{:02}.txt", day)
+}
+
+fn find(id: &str) -> (&'static dyn Puzzle, String) {
+    let id:PuzzleId = id.parse().unwrap();
+    (find_puzzle(id.year, id.day).unwrap(), input_file(id.day))
+}
+
+/// The input is read once, outside the timed loop, so only parsing is timed
+#[divan::bench(args=days())]
+fn parse(bencher: Bencher, id: &str) {
+    let (puzzle, file_name) = find(id);
+    let text = read_year_input(puzzle.year(), &file_name).unwrap();
+    bencher.bench(|| puzzle.parse(&text).unwrap());
+}
+
+/// The input is prepared once, outside the timed loop
+fn bench_part(bencher: Bencher, id: &str, part: Part) {
+    let (puzzle, file_name) = find(id);
+    let input = puzzle.load(&file_name).unwrap();
+    bencher.bench_local(|| puzzle.solve(part, input.as_ref()));
+}
+
+#[divan::bench(name="part_1", args=days())]
+fn bench_part_1(bencher: Bencher, id: &str) {
+    bench_part(bencher, id, Part::One);
+}
+
+#[divan::bench(name="part_2", args=days())]
+fn bench_part_2(bencher: Bencher, id: &str) {
+    bench_part(bencher, id, Part::Two);
+}
@@ -0,0 +1,161 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The answer to one part of a puzzle
+#[derive(Clone, Debug, Default)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    /// For puzzles whose answer is text rather than a number
+    Text(String),
+    /// The part has not been solved yet
+    #[default]
+    NotImplemented,
+}
+impl Answer {
+    pub fn is_implemented(&self) -> bool {
+        !matches!(self, Answer::NotImplemented)
+    }
+}
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+/// Numbers parse as unsigned if they can, then signed, anything else is text
+/// and an empty string is not implemented
+impl FromStr for Answer {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(if s.is_empty() {
+            Answer::NotImplemented
+        } else if let Ok(n) = s.parse() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Signed(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+/// Numbers compare by value whether signed or not, text compares with text,
+/// and different kinds of answer don't compare
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => Some(a.cmp(b)),
+            (Answer::Signed(a), Answer::Signed(b)) => Some(a.cmp(b)),
+            (Answer::Signed(a), Answer::Unsigned(b)) => Some(compare_signed(*a, *b)),
+            (Answer::Unsigned(a), Answer::Signed(b)) => Some(compare_signed(*b, *a).reverse()),
+            (Answer::Text(a), Answer::Text(b)) => Some(a.cmp(b)),
+            (Answer::NotImplemented, Answer::NotImplemented) => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+impl Eq for Answer {}
+
+fn compare_signed(a: i128, b: u128) -> Ordering {
+    match u128::try_from(a) {
+        Ok(a) => a.cmp(&b),
+        Err(_) => Ordering::Less,
+    }
+}
+
+/// Answers are written as text, so large numbers survive JSON, and not implemented is empty
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::NotImplemented => serializer.serialize_none(),
+            answer => serializer.collect_str(answer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text:Option<String> = Option::deserialize(deserializer)?;
+        Ok(text.map(|text| text.parse().unwrap_or_default()).unwrap_or_default())
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $wide:ty, $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::$variant(n as $wide)
+            }
+        })*
+    };
+}
+answer_from!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+answer_from!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+/// Parts without an answer yet return `None`
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Answer {
+        answer.map(Into::into).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("42".parse::<Answer>().unwrap(), Answer::Unsigned(42));
+        assert_eq!("-7".parse::<Answer>().unwrap(), Answer::Signed(-7));
+        assert_eq!("340282366920938463463374607431768211455".parse::<Answer>().unwrap(), Answer::Unsigned(u128::MAX));
+        assert_eq!("EXAMPLE".parse::<Answer>().unwrap(), Answer::Text("EXAMPLE".to_string()));
+        assert_eq!("".parse::<Answer>().unwrap(), Answer::NotImplemented);
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(Answer::Signed(42), Answer::Unsigned(42));
+        assert_eq!(Answer::from(42usize), Answer::from(42isize));
+        assert!(Answer::Signed(-1) < Answer::Unsigned(0));
+        assert!(Answer::Unsigned(u128::MAX) > Answer::Signed(i128::MAX));
+        assert!(Answer::Text("abc".to_string()) < Answer::Text("abd".to_string()));
+        assert_ne!(Answer::Text("42".to_string()), Answer::Unsigned(42));
+        assert_eq!(Answer::Unsigned(0).partial_cmp(&Answer::NotImplemented), None);
+        assert_eq!(Answer::from(None::<u32>), Answer::NotImplemented);
+        assert_eq!(Answer::from(Some(-3isize)), Answer::Signed(-3));
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(serde_json::to_string(&Answer::Unsigned(u128::MAX)).unwrap(), "\"340282366920938463463374607431768211455\"");
+        assert_eq!(serde_json::to_string(&Answer::NotImplemented).unwrap(), "null");
+        assert_eq!(serde_json::from_str::<Answer>("\"-12\"").unwrap(), Answer::Signed(-12));
+        assert_eq!(serde_json::from_str::<Answer>("null").unwrap(), Answer::NotImplemented);
+        assert_eq!(Answer::Signed(-12).to_string(), "-12");
+    }
+}
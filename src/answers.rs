@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Display;
+use std::path::Path;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize, Serializer};
+use crate::answer::Answer;
+use crate::year_path;
+use crate::solution::Part;
+
+/// Name of the known answers file in each year's directory
+pub const ANSWERS_FILE: &str = "answers.csv";
+
+/// A confirmed answer for one part of a day against one input file
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: Answer,
+}
+
+/// How an answer compares with the known answer for the same day, part and input
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum Verification {
+    Verified,
+    Mismatch { expected: Answer },
+    #[default]
+    Unknown,
+}
+impl Verification {
+    /// Returns the expected answer of a mismatch
+    pub fn expected(&self) -> Option<&Answer> {
+        match self {
+            Verification::Mismatch { expected } => Some(expected),
+            _ => None,
+        }
+    }
+}
+impl Serialize for Verification {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+impl Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verification::Verified => write!(f, "verified"),
+            Verification::Mismatch { .. } => write!(f, "mismatch"),
+            Verification::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Every known answer, keyed by day, part and input file name
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u8, Part, String), Answer>,
+}
+impl Answers {
+    /// Loads a year's `answers.csv`
+    /// A missing file has no known answers
+    pub fn load(year: u16) -> Result<Answers> {
+        let path = year_path(year, ANSWERS_FILE)?;
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        Answers::from_path(&path)
+    }
+
+    pub fn from_path(path: &Path) -> Result<Answers> {
+        let mut reader = csv::Reader::from_path(path)
+            .with_context(|| format!("unable to read answers file {}", path.display()))?;
+        let mut answers = Answers::default();
+        for row in reader.deserialize() {
+            let known:KnownAnswer = row
+                .with_context(|| format!("invalid answers file {}", path.display()))?;
+            answers.insert(known);
+        }
+        Ok(answers)
+    }
+
+    /// Writes every known answer, ordered by day, part and input
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut writer = csv::Writer::from_path(path)
+            .with_context(|| format!("unable to write answers file {}", path.display()))?;
+        for known in self.iter() {
+            writer.serialize(known)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn insert(&mut self, known: KnownAnswer) {
+        self.answers.insert((known.day, known.part, known.input), known.answer);
+    }
+
+    /// Returns the known answer for a day, part and input file
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&Answer> {
+        self.answers.get(&(day, part, input.to_string()))
+    }
+
+    /// Compares an answer against the known answer
+    pub fn verify(&self, day: u8, part: Part, input: &str, answer: &Answer) -> Verification {
+        match self.get(day, part, input) {
+            Some(expected) if expected == answer => Verification::Verified,
+            Some(expected) => Verification::Mismatch { expected: expected.clone() },
+            None => Verification::Unknown,
+        }
+    }
+
+    /// Returns every known answer, ordered by day, part and input
+    pub fn iter(&self) -> impl Iterator<Item = KnownAnswer> + '_ {
+        let mut known:Vec<KnownAnswer> = self.answers
+            .iter()
+            .map(|((day, part, input), answer)| KnownAnswer {
+                day: *day,
+                part: *part,
+                input: input.clone(),
+                answer: answer.clone(),
+            })
+            .collect();
+        known.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+        known.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{find_puzzle, YEARS};
+    use crate::runner::run;
+
+    #[test]
+    fn test_verify() {
+        let mut answers = Answers::default();
+        answers.insert(KnownAnswer { day: 5, part: Part::One, input: "day05.txt".to_string(), answer: Answer::Unsigned(462648396) });
+        assert_eq!(answers.verify(5, Part::One, "day05.txt", &Answer::Unsigned(462648396)), Verification::Verified);
+        assert_eq!(answers.verify(5, Part::One, "day05.txt", &Answer::Signed(462648396)), Verification::Verified);
+        assert_eq!(answers.verify(5, Part::One, "day05.txt", &Answer::Unsigned(0)), Verification::Mismatch { expected: Answer::Unsigned(462648396) });
+        assert_eq!(answers.verify(5, Part::One, "day05.txt", &Answer::NotImplemented), Verification::Mismatch { expected: Answer::Unsigned(462648396) });
+        assert_eq!(answers.verify(5, Part::Two, "day05.txt", &Answer::Unsigned(0)), Verification::Unknown);
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(ANSWERS_FILE);
+        let mut answers = Answers::default();
+        answers.insert(KnownAnswer { day: 5, part: Part::Two, input: "day05.txt".to_string(), answer: Answer::Unsigned(2520479) });
+        answers.insert(KnownAnswer { day: 5, part: Part::One, input: "day05.txt".to_string(), answer: Answer::Unsigned(462648396) });
+        answers.save(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "day,part,input,answer\n5,1,day05.txt,462648396\n5,2,day05.txt,2520479\n");
+        assert_eq!(Answers::from_path(&path).unwrap().iter().collect::<Vec<_>>(), answers.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_known_answers() {
+        // every known answer still holds, skipping puzzle inputs that aren't committed
+        assert!(Answers::load(2023).unwrap().get(5, Part::One, "day05-example.txt").is_some());
+        for year in YEARS.iter() {
+            for known in Answers::load(year.year).unwrap().iter() {
+                let puzzle = find_puzzle(year.year, known.day).unwrap();
+                let example = known.input.starts_with(&format!("day{:02}-example", known.day));
+                let results = match run(puzzle, &known.input, &[known.part]) {
+                    Ok(results) => results,
+                    Err(_) if !example => continue,
+                    Err(error) => panic!("{} {:?}: {:#}", year.year, known, error),
+                };
+                assert_eq!(results.parts[0].answer, known.answer, "{} {:?}", year.year, known);
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+use std::fmt::Display;
+use crate::answers::Answers;
+use crate::find_puzzle;
+use crate::solution::Part;
+
+/// Where a day of the season stands
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DayState {
+    /// Not implemented yet
+    Pending,
+    /// Implemented, but without a known answer to both parts of the puzzle input
+    Incomplete,
+    /// Both parts of the puzzle input have a known answer
+    Complete,
+}
+impl DayState {
+    pub const ALL: [DayState; 3] = [DayState::Complete, DayState::Incomplete, DayState::Pending];
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            DayState::Pending => "🎁",
+            DayState::Incomplete => "❄️",
+            DayState::Complete => "🌟",
+        }
+    }
+}
+impl Display for DayState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayState::Pending => write!(f, "pending"),
+            DayState::Incomplete => write!(f, "incomplete"),
+            DayState::Complete => write!(f, "complete"),
+        }
+    }
+}
+
+/// One day of the calendar
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CalendarDay {
+    pub day: u8,
+    /// The puzzle title, for implemented days
+    pub title: Option<&'static str>,
+    /// Parts of the puzzle input with a known answer
+    pub stars: u8,
+    pub state: DayState,
+}
+
+/// Returns every day of a year's season, from the registry and the year's known answers
+pub fn calendar(year: u16, answers: &Answers) -> Vec<CalendarDay> {
+    (1..=25)
+        .map(|day| {
+            let title = find_puzzle(year, day).map(|puzzle| puzzle.title());
+            let input = format!("day{:02}.txt", day);
+            let stars = Part::ALL
+                .iter()
+                .filter(|part| answers.get(day, **part, &input).is_some())
+                .count() as u8;
+            let state = match (title, stars) {
+                (None, _) => DayState::Pending,
+                (Some(_), 2) => DayState::Complete,
+                (Some(_), _) => DayState::Incomplete,
+            };
+            CalendarDay { day, title, stars, state }
+        })
+        .collect()
+}
+
+/// Returns the legend of day states, with how many days are in each
+/// e.g. `🌟 complete 6  ❄️ incomplete 8  🎁 pending 11`
+pub fn legend(days: &[CalendarDay]) -> String {
+    DayState::ALL
+        .iter()
+        .map(|state| {
+            let count = days.iter().filter(|d| d.state == *state).count();
+            format!("{} {} {}", state.emoji(), state, count)
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::answers::KnownAnswer;
+
+    #[test]
+    fn test_calendar() {
+        let mut answers = Answers::default();
+        answers.insert(KnownAnswer { day: 5, part: Part::One, input: "day05.txt".to_string(), answer: Answer::Unsigned(462648396) });
+        answers.insert(KnownAnswer { day: 5, part: Part::Two, input: "day05.txt".to_string(), answer: Answer::Unsigned(2520479) });
+        answers.insert(KnownAnswer { day: 6, part: Part::One, input: "day06-example.txt".to_string(), answer: Answer::Unsigned(288) });
+        let days = calendar(2023, &answers);
+        assert_eq!(days.len(), 25);
+        assert_eq!(days[4], CalendarDay { day: 5, title: Some("If You Give A Seed A Fertilizer"), stars: 2, state: DayState::Complete });
+        assert_eq!(days[5].state, DayState::Incomplete);
+        assert_eq!(days[5].stars, 0);
+        assert_eq!(days[12].state, DayState::Pending);
+        assert_eq!(days[24].title, None);
+
+        let implemented = crate::y2023::DAYS.len();
+        assert_eq!(legend(&days), format!("🌟 complete 1  ❄️ incomplete {}  🎁 pending {}", implemented - 1, 25 - implemented));
+        assert!(calendar(2015, &answers).iter().all(|day| day.state == DayState::Pending));
+    }
+}
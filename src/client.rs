@@ -0,0 +1,247 @@
+use std::fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use thiserror::Error;
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::solution::Part;
+use crate::submit::Response;
+
+/// The puzzle site, unless the config says otherwise
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the puzzle site, as its maintainers ask automated tools to do
+pub const USER_AGENT: &str = concat!(
+    "github.com/vwallen/advent-of-code-2023-rust by vwallen@antlab.net (",
+    env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"), ")",
+);
+
+/// Reasons a request to the puzzle site failed
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("no session token, set AOC_SESSION or session in aoc.toml")]
+    NoSession,
+
+    #[error("day {0} has not been unlocked yet")]
+    NotUnlocked(u8),
+
+    #[error("the session token was rejected, it may have expired")]
+    BadSession,
+
+    #[error("{url} responded with status {status}")]
+    Status { url: String, status: u16 },
+
+    #[error("request to {url} failed")]
+    Transport {
+        url: String,
+        #[source]
+        source: Box<ureq::Transport>,
+    },
+
+    #[error("response from {url} could not be read")]
+    Body {
+        url: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// A logged in client for the puzzle site
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: u16,
+}
+impl Client {
+    pub fn new(session: &str, base_url: &str, year: u16) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+        }
+    }
+
+    /// Returns a client for a year's puzzles, using the session token and base URL
+    /// from the environment or config file
+    pub fn from_config(config: &Config, year: u16) -> Result<Client, ClientError> {
+        let session = config.session().ok_or(ClientError::NoSession)?;
+        let base_url = config.base_url().unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&session, &base_url, year))
+    }
+
+    /// Returns the URL of a path under the year, e.g. `day/5/input`
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}/{}", self.base_url, self.year, path)
+    }
+
+    /// Downloads a day's puzzle input
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let url = self.url(&format!("day/{}/input", day));
+        let request = self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        self.read(day, &url, request.call())
+    }
+
+    /// Submits an answer for one part of a day, and reads the response page
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<Response, ClientError> {
+        let url = self.url(&format!("day/{}/answer", day));
+        let level = part.number().to_string();
+        let answer = answer.to_string();
+        let request = self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        let page = self.read(day, &url, request.send_form(&[("level", &level), ("answer", &answer)]))?;
+        Ok(Response::parse(&page))
+    }
+
+    /// Returns the body of a successful response, or what went wrong
+    fn read(&self, day: u8, url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|source| ClientError::Body { url: url.to_string(), source }),
+            Err(ureq::Error::Status(404, _)) => Err(ClientError::NotUnlocked(day)),
+            Err(ureq::Error::Status(400, _)) => Err(ClientError::BadSession),
+            Err(ureq::Error::Status(status, _)) => Err(ClientError::Status { url: url.to_string(), status }),
+            Err(ureq::Error::Transport(source)) => Err(ClientError::Transport {
+                url: url.to_string(),
+                source: Box::new(source),
+            }),
+        }
+    }
+}
+
+/// Whether an input had to be downloaded
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads a day's input to `path`, unless it has already been downloaded
+/// The client is only created when a download is needed
+pub fn fetch_input(client: impl FnOnce() -> Result<Client, ClientError>, day: u8, path: &Path) -> Result<Fetched> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+    let input = client()?.input(day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // write to a temporary file first, so an interrupted write isn't mistaken for a cached input
+    let partial = path.with_extension("part");
+    fs::write(&partial, input)
+        .with_context(|| format!("unable to write {}", partial.display()))?;
+    fs::rename(&partial, path)
+        .with_context(|| format!("unable to write {}", path.display()))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::submit::Outcome;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::thread::JoinHandle;
+
+    /// Serves a single canned response on a local port
+    /// Returns the base URL and a handle yielding the request that was received
+    pub(crate) fn serve(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let (base_url, handle) = serve_many(status, body, 1);
+        (base_url, thread::spawn(move || handle.join().unwrap().remove(0)))
+    }
+
+    /// Serves the same canned response to `count` requests, one after another
+    /// Returns the base URL and a handle yielding the requests that were received
+    pub(crate) fn serve_many(status: u16, body: &str, count: usize) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for _ in 0..count {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() { break; }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                write!(stream, "HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = serve(200, "1abc2\npqr3stu8vwx\n");
+        let client = Client::new("abc123", &base_url, 2023);
+        assert_eq!(client.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (base_url, server) = serve(404, "Please don't repeatedly request this endpoint before it unlocks!");
+        let client = Client::new("abc123", &base_url, 2023);
+        assert!(matches!(client.input(25), Err(ClientError::NotUnlocked(25))));
+        server.join().unwrap();
+
+        let (base_url, server) = serve(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.");
+        let client = Client::new("expired", &base_url, 2023);
+        assert!(matches!(client.input(1), Err(ClientError::BadSession)));
+        server.join().unwrap();
+
+        let (base_url, server) = serve(500, "");
+        let client = Client::new("abc123", &base_url, 2023);
+        assert!(matches!(client.input(1), Err(ClientError::Status { status: 500, .. })));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = serve(200, "<article><p>That's the right answer!</p></article>");
+        let client = Client::new("abc123", &base_url, 2023);
+        assert_eq!(client.submit(5, Part::Two, &Answer::Unsigned(46)).unwrap().outcome, Outcome::Correct);
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=46"));
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day01.txt");
+        let (base_url, server) = serve(200, "1abc2\n");
+        let connect = || Ok(Client::new("abc123", &base_url, 2023));
+        assert_eq!(fetch_input(connect, 1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\n");
+        server.join().unwrap();
+
+        // the server has gone, so this only passes if nothing is requested
+        let connect = || Ok(Client::new("abc123", &base_url, 2023));
+        assert_eq!(fetch_input(connect, 1, &path).unwrap(), Fetched::Cached);
+        assert_eq!(fetch_input(|| Err(ClientError::NoSession), 1, &path).unwrap(), Fetched::Cached);
+    }
+}
@@ -0,0 +1,92 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Name of the config file, looked for in the current directory
+pub const CONFIG_FILE: &str = "aoc.toml";
+/// Environment variable holding the path of the config file, to use instead of `aoc.toml`
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+/// Environment variable holding the adventofcode.com session token
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable holding the base URL of the puzzle site
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Environment variable holding the input directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Settings read from the config file, e.g.
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// input_dir = "../inputs"
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    /// Relative to the config file
+    pub input_dir: Option<PathBuf>,
+}
+impl Config {
+    /// Loads the file named by `AOC_CONFIG`, or `aoc.toml` if it exists
+    pub fn load() -> Result<Config> {
+        match env::var_os(CONFIG_VAR) {
+            Some(path) => Config::from_path(&PathBuf::from(path)),
+            None if Path::new(CONFIG_FILE).exists() => Config::from_path(Path::new(CONFIG_FILE)),
+            None => Ok(Config::default()),
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Result<Config> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("unable to read config file {}", path.display()))?;
+        let mut config:Config = toml::from_str(&text)
+            .with_context(|| format!("invalid config file {}", path.display()))?;
+        if let (Some(input_dir), Some(parent)) = (config.input_dir.as_mut(), path.parent()) {
+            *input_dir = parent.join(&*input_dir);
+        }
+        Ok(config)
+    }
+
+    /// Returns the session token from `AOC_SESSION`, then the config file
+    pub fn session(&self) -> Option<String> {
+        env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+
+    /// Returns the base URL from `AOC_BASE_URL`, then the config file
+    pub fn base_url(&self) -> Option<String> {
+        env::var(BASE_URL_VAR)
+            .ok()
+            .or_else(|| self.base_url.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE);
+        fs::write(&path, "session = \"abc123\"\nbase_url = \"http://127.0.0.1:8080\"\n").unwrap();
+        let config = Config::from_path(&path).unwrap();
+        assert_eq!(config.session, Some("abc123".to_string()));
+        assert_eq!(config.base_url, Some("http://127.0.0.1:8080".to_string()));
+        assert_eq!(config.input_dir, None);
+
+        fs::write(&path, "input_dir = \"inputs\"\n").unwrap();
+        assert_eq!(Config::from_path(&path).unwrap().input_dir, Some(dir.path().join("inputs")));
+
+        fs::write(&path, "sesion = \"abc123\"\n").unwrap();
+        assert!(Config::from_path(&path).is_err());
+        assert!(Config::from_path(&dir.path().join("missing.toml")).is_err());
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+use std::io;
+use std::panic::{self, PanicHookInfo};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::ExecutableCommand;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use crate::answer::Answer;
+use crate::answers::{Answers, Verification};
+use crate::calendar::{calendar, legend, CalendarDay};
+use crate::report::{Record, Status};
+use crate::runner::{self, InputSource};
+use crate::solution::Part;
+use crate::find_puzzle;
+
+/// Height of a day in the calendar grid, including its border
+const CELL_HEIGHT: u16 = 5;
+
+/// What the dashboard should do after a key press
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Continue,
+    /// A day was run, and may have written over the screen
+    Redraw,
+    Quit,
+}
+
+/// The state of the calendar dashboard
+pub struct Dashboard {
+    year: u16,
+    answers: Answers,
+    days: Vec<CalendarDay>,
+    selected: u8,
+    /// The records of the last run of each day
+    records: HashMap<u8, Vec<Record>>,
+    /// The visualization of the last run of the selected day, if it has one
+    visualization: Option<String>,
+}
+impl Dashboard {
+    pub fn new(year: u16, answers: Answers) -> Dashboard {
+        Dashboard {
+            year,
+            days: calendar(year, &answers),
+            answers,
+            selected: 1,
+            records: HashMap::new(),
+            visualization: None,
+        }
+    }
+
+    pub fn selected(&self) -> u8 {
+        self.selected
+    }
+
+    /// Selects a day, clamped to the season
+    pub fn select(&mut self, day: i16) {
+        let day = day.clamp(1, 25) as u8;
+        if day != self.selected {
+            self.selected = day;
+            self.visualization = None;
+        }
+    }
+
+    /// Handles a key press: arrows or hjkl move around the calendar,
+    /// e runs the selected day on its examples, r on its puzzle input, q quits
+    pub fn key(&mut self, code: KeyCode) -> Action {
+        let day = self.selected as i16;
+        match code {
+            KeyCode::Left | KeyCode::Char('h') => self.select(day - 1),
+            KeyCode::Right | KeyCode::Char('l') => self.select(day + 1),
+            KeyCode::Up | KeyCode::Char('k') => self.select(day - 5),
+            KeyCode::Down | KeyCode::Char('j') => self.select(day + 5),
+            KeyCode::Char('e') => {
+                self.run_selected(&InputSource::Examples);
+                return Action::Redraw;
+            },
+            KeyCode::Char('r') => {
+                self.run_selected(&InputSource::Puzzle);
+                return Action::Redraw;
+            },
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            _ => {},
+        }
+        Action::Continue
+    }
+
+    /// Runs the selected day on every file of the source, replacing its last records
+    pub fn run_selected(&mut self, source: &InputSource) {
+        let Some(puzzle) = find_puzzle(self.year, self.selected) else { return };
+        let files = source.files(self.year, puzzle.day());
+        let records = files
+            .iter()
+            .flat_map(|file_name| match runner::run(puzzle, file_name, &Part::ALL) {
+                Ok(run) => Record::from_run(self.year, puzzle.day(), puzzle.title(), &run, &self.answers),
+                Err(_) => Record::from_error(self.year, puzzle.day(), puzzle.title(), file_name, &Part::ALL),
+            })
+            .collect();
+        self.records.insert(puzzle.day(), records);
+        self.visualization = files
+            .first()
+            .and_then(|file_name| visualize(self.year, puzzle.day(), file_name))
+            .map(|rendered| rendered.unwrap_or_else(|error| format!("{:#}", error)));
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let [main, footer] = split(Direction::Vertical, frame.size(), [Constraint::Min(0), Constraint::Length(1)]);
+        let [grid, side] = split(Direction::Horizontal, main, [Constraint::Length(5 * 14), Constraint::Min(0)]);
+        let [details, pane] = split(Direction::Vertical, side, [Constraint::Length(10), Constraint::Min(0)]);
+
+        for (row, week) in self.days.chunks(5).enumerate() {
+            for (col, day) in week.iter().enumerate() {
+                let area = Rect {
+                    x: grid.x + col as u16 * grid.width / 5,
+                    y: grid.y + row as u16 * CELL_HEIGHT,
+                    width: grid.width / 5,
+                    height: CELL_HEIGHT,
+                };
+                frame.render_widget(self.cell(day), area.intersection(grid));
+            }
+        }
+        frame.render_widget(self.details(), details);
+        let visualization = self.visualization.as_deref().map(strip_ansi).unwrap_or_default();
+        frame.render_widget(
+            Paragraph::new(visualization).block(Block::default().borders(Borders::ALL).title("Visualization")),
+            pane,
+        );
+        let help = format!("{}   ←↓↑→ select  e examples  r puzzle input  q quit", legend(&self.days));
+        frame.render_widget(Paragraph::new(help), footer);
+    }
+
+    fn cell(&self, day: &CalendarDay) -> Paragraph<'static> {
+        let stars = "★".repeat(day.stars as usize) + &"☆".repeat(2 - day.stars as usize);
+        let (time, verified) = match self.records.get(&day.day) {
+            Some(records) => (total_time(records), verification(records)),
+            None => (String::new(), ""),
+        };
+        let style = match day.day == self.selected {
+            true => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            false => Style::default(),
+        };
+        Paragraph::new(vec![
+            Line::from(stars),
+            Line::from(format!("{} {}", time, verified)),
+        ])
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(style)
+            .title(format!("{} Day {}", day.state.emoji(), day.day)))
+    }
+
+    fn details(&self) -> Paragraph<'static> {
+        let day = &self.days[self.selected as usize - 1];
+        let mut lines = vec![
+            Line::from(day.title.unwrap_or("Not implemented yet").to_string()),
+            Line::from(format!("{} {}, {} of 2 stars", day.state.emoji(), day.state, day.stars)),
+        ];
+        for record in self.records.get(&day.day).into_iter().flatten() {
+            let answer = match (&record.status, &record.answer) {
+                (Status::Error, _) => "unreadable input".to_string(),
+                (_, Answer::NotImplemented) => "no answer".to_string(),
+                (_, answer) => answer.to_string(),
+            };
+            let time = Duration::from_micros(record.solve_us.unwrap_or(0));
+            let verification = match &record.verification {
+                Verification::Verified => "✔".to_string(),
+                Verification::Mismatch { expected } => format!("✘ expected {}", expected),
+                Verification::Unknown => String::new(),
+            };
+            lines.push(Line::from(format!("{} part {}  {}  {:.2?} {}", record.input, record.part, answer, time, verification)));
+        }
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(format!("{} Day {}", self.year, day.day)))
+    }
+}
+
+fn split<const N: usize>(direction: Direction, area: Rect, constraints: [Constraint; N]) -> [Rect; N] {
+    let areas = Layout::default().direction(direction).constraints(constraints).split(area);
+    std::array::from_fn(|i| areas[i])
+}
+
+/// Returns the total parse and solve time of a day's records
+fn total_time(records: &[Record]) -> String {
+    let mut us = 0;
+    let mut parsed = None;
+    for record in records.iter() {
+        if parsed != Some(&record.input) {
+            us += record.parse_us.unwrap_or(0);
+            parsed = Some(&record.input);
+        }
+        us += record.solve_us.unwrap_or(0);
+    }
+    format!("{:.2?}", Duration::from_micros(us))
+}
+
+/// Returns ✘ if any record has the wrong answer, ✔ if every record is verified
+fn verification(records: &[Record]) -> &'static str {
+    if records.iter().any(|r| matches!(r.verification, Verification::Mismatch { .. })) {
+        "✘"
+    } else if records.iter().all(|r| r.verification == Verification::Verified) {
+        "✔"
+    } else {
+        ""
+    }
+}
+
+/// Returns the visualization of a day on an input, for days that have one
+pub fn visualize(year: u16, day: u8, file_name: &str) -> Option<Result<String>> {
+    find_puzzle(year, day)?.visualize(file_name)
+}
+
+/// Removes the terminal color codes from text
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Send + Sync>;
+
+/// The terminal in raw mode on the alternate screen, restored when dropped
+/// A panic, e.g. in a day being run, restores it before the panic is reported
+struct Screen {
+    /// The panic hook from before the screen was entered, put back when it is dropped
+    previous: Option<Arc<PanicHook>>,
+}
+impl Screen {
+    fn enter() -> Result<Screen> {
+        let previous = Arc::new(panic::take_hook());
+        let report = Arc::clone(&previous);
+        panic::set_hook(Box::new(move |info| {
+            restore();
+            report(info);
+        }));
+        let screen = Screen { previous: Some(previous) };
+        terminal::enable_raw_mode()?;
+        io::stdout().execute(EnterAlternateScreen)?;
+        Ok(screen)
+    }
+}
+impl Drop for Screen {
+    fn drop(&mut self) {
+        restore();
+        // the hook can't be changed while panicking, when ours has already reported the panic
+        if thread::panicking() {
+            return;
+        }
+        // dropping our hook leaves the previous one with a single owner
+        drop(panic::take_hook());
+        if let Some(previous) = self.previous.take().and_then(|previous| Arc::try_unwrap(previous).ok()) {
+            panic::set_hook(previous);
+        }
+    }
+}
+
+fn restore() {
+    let _ = terminal::disable_raw_mode();
+    let _ = io::stdout().execute(LeaveAlternateScreen);
+}
+
+/// Shows a year's dashboard in the terminal until it is quit
+pub fn run(year: u16) -> Result<()> {
+    let mut dashboard = Dashboard::new(year, Answers::load(year)?);
+    let _screen = Screen::enter()?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let mut shown = || -> Result<()> {
+        loop {
+            terminal.draw(|frame| dashboard.draw(frame))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match dashboard.key(key.code) {
+                    Action::Continue => {},
+                    Action::Redraw => terminal.clear()?,
+                    Action::Quit => return Ok(()),
+                }
+            }
+        }
+    };
+    shown()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    fn screen(dashboard: &Dashboard) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(140, 30)).unwrap();
+        terminal.draw(|frame| dashboard.draw(frame)).unwrap();
+        terminal.backend().to_string()
+    }
+
+    #[test]
+    fn test_key() {
+        let mut dashboard = Dashboard::new(2023, Answers::default());
+        assert_eq!(dashboard.key(KeyCode::Left), Action::Continue);
+        assert_eq!(dashboard.selected(), 1);
+        dashboard.key(KeyCode::Down);
+        dashboard.key(KeyCode::Char('l'));
+        assert_eq!(dashboard.selected(), 7);
+        for _ in 0..5 {
+            dashboard.key(KeyCode::Char('j'));
+        }
+        assert_eq!(dashboard.selected(), 25);
+        assert_eq!(dashboard.key(KeyCode::Char('q')), Action::Quit);
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut dashboard = Dashboard::new(2023, Answers::load(2023).unwrap());
+        let drawn = screen(&dashboard);
+        assert!(drawn.contains("Day 25"));
+        assert!(drawn.contains("Trebuchet?!"));
+
+        dashboard.select(2);
+        assert_eq!(dashboard.key(KeyCode::Char('e')), Action::Redraw);
+        let drawn = screen(&dashboard);
+        assert!(drawn.contains("Cube Conundrum"));
+        assert!(drawn.contains("day02-example.txt part 1  8"));
+        assert!(drawn.contains("2023 Day 2"));
+    }
+
+    #[test]
+    fn test_visualize() {
+        assert!(visualize(2023, 2, "day02-example.txt").is_none());
+        let rendered = strip_ansi(&visualize(2023, 10, "day10-example-2.txt").unwrap().unwrap());
+        assert!(rendered.contains("■"));
+        assert!(!rendered.contains('\x1b'));
+        assert!(visualize(2023, 14, "day14-example.txt").unwrap().is_ok());
+        assert!(visualize(2015, 1, "day01.txt").is_none());
+        assert!(visualize(2023, 14, "day14-missing.txt").unwrap().is_err());
+    }
+}
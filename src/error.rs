@@ -0,0 +1,54 @@
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Reasons an input file could not be loaded
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("input file {0} was not found")]
+    NotFound(PathBuf),
+
+    #[error("input file {path} could not be read")]
+    Unreadable {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("input file {0} is empty")]
+    Empty(PathBuf),
+
+    #[error("input file {0} is not valid UTF-8")]
+    InvalidUtf8(PathBuf),
+
+    #[error("no input directory was found, tried {}", list_paths(.0))]
+    NoInputDir(Vec<PathBuf>),
+
+    #[error("input directory {} given by {given_by} does not exist", .path.display())]
+    MissingInputDir { path: PathBuf, given_by: String },
+
+    #[error("{0:#}")]
+    Config(anyhow::Error),
+}
+impl InputError {
+    /// Returns the path of the input file that failed to load, if it got as far as a file
+    pub fn path(&self) -> Option<&PathBuf> {
+        match self {
+            InputError::NotFound(path) => Some(path),
+            InputError::Unreadable { path, .. } => Some(path),
+            InputError::Empty(path) => Some(path),
+            InputError::InvalidUtf8(path) => Some(path),
+            InputError::NoInputDir(_) => None,
+            InputError::MissingInputDir { .. } => None,
+            InputError::Config(_) => None,
+        }
+    }
+}
+
+fn list_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,256 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context, Result};
+use crate::answer::Answer;
+use crate::answers::{Answers, KnownAnswer, ANSWERS_FILE};
+use crate::solution::Part;
+
+/// What one part of a saved puzzle page shows
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PartPage {
+    /// Every `<pre><code>` block, unescaped
+    pub examples: Vec<String>,
+    /// The last emphasized code in the part, which is the answer for its first example
+    pub answer: Option<Answer>,
+}
+
+/// Returns each part described by a saved puzzle page, one per `<article>`
+pub fn parse(html: &str) -> Vec<PartPage> {
+    html.split("<article")
+        .skip(1)
+        .map(|article| {
+            let article = article.split("</article>").next().unwrap_or(article);
+            let examples = between(article, "<pre><code>", "</code></pre>")
+                .map(|block| unescape(&strip_tags(block)))
+                .collect();
+            let answer = between(article, "<code><em>", "</em></code>")
+                .last()
+                .map(|answer| unescape(&strip_tags(answer)).parse().unwrap_or_default());
+            PartPage { examples, answer }
+        })
+        .collect()
+}
+
+/// Returns every piece of text between `start` and `end`
+fn between<'a>(text: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    text.split(start)
+        .skip(1)
+        .filter_map(move |rest| rest.split_once(end).map(|(inside, _)| inside))
+}
+
+fn strip_tags(text: &str) -> String {
+    let mut output = String::new();
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => output.push(c),
+            _ => (),
+        }
+    }
+    output
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Returns the file name of a day's nth example, counting from 0
+/// The first is `dayNN-example.txt`, the rest `dayNN-example-2.txt` and so on
+fn example_name(day: u8, n: usize) -> String {
+    match n {
+        0 => format!("day{:02}-example.txt", day),
+        n => format!("day{:02}-example-{}.txt", day, n + 1),
+    }
+}
+
+/// The files `extract_day` wrote, and warnings about answers it had to guess at or leave out
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Extracted {
+    pub written: Vec<PathBuf>,
+    pub warnings: Vec<String>,
+}
+
+/// Seeds a day of a year under the crate `root` from its saved puzzle page:
+/// writes the examples to the year's input directory `year_dir`, adds each part's answer for the
+/// first example of that part to the year's known answers, and fills in the example tests of a
+/// module still as generated by `new`. Later code blocks of a part are usually illustrations, so a
+/// part with several gets a warning. A part without examples of its own uses part one's first example
+/// only with `reuse_example`, and otherwise has its answer left out with a warning.
+/// Example files that already have content are left alone, as are existing known answers.
+pub fn extract_day(root: &Path, year_dir: &Path, year: u16, day: u8, html: &str, reuse_example: bool) -> Result<Extracted> {
+    let parts = parse(html);
+    if parts.iter().all(|part| part.examples.is_empty()) {
+        bail!("the page has no examples");
+    }
+    let answers_path = year_dir.join(ANSWERS_FILE);
+    let module_path = root.join("src").join(format!("y{}", year)).join(format!("day{:02}.rs", day));
+    fs::create_dir_all(year_dir)?;
+
+    let mut written = vec![];
+    let mut warnings = vec![];
+    let mut answers = match answers_path.exists() {
+        true => Answers::from_path(&answers_path)?,
+        false => Answers::default(),
+    };
+    let mut module = fs::read_to_string(&module_path).ok();
+    let mut examples = 0;
+    let mut first_example:Option<String> = None;
+
+    for (part, page) in Part::ALL.into_iter().zip(parts.iter()) {
+        let mut files = vec![];
+        for example in page.examples.iter() {
+            let file_name = example_name(day, examples);
+            let path = year_dir.join(&file_name);
+            if fs::read_to_string(&path).map(|text| text.trim().is_empty()).unwrap_or(true) {
+                fs::write(&path, example)
+                    .with_context(|| format!("unable to write {}", path.display()))?;
+                written.push(path);
+            }
+            examples += 1;
+            files.push(file_name);
+        }
+        let answer = page.answer.as_ref();
+        let example = match (files.first(), first_example.as_ref()) {
+            (Some(file_name), _) => {
+                if answer.is_some() && files.len() > 1 {
+                    warnings.push(format!("part {} has {} code blocks, its answer is for the first, {}", part, files.len(), file_name));
+                }
+                Some(file_name)
+            },
+            (None, Some(file_name)) if reuse_example => Some(file_name),
+            (None, Some(file_name)) if answer.is_some() => {
+                warnings.push(format!("part {} has no example of its own, its answer is left out unless reused for {}", part, file_name));
+                None
+            },
+            (None, _) => {
+                if answer.is_some() {
+                    warnings.push(format!("part {} has no example, its answer is left out", part));
+                }
+                None
+            },
+        };
+        if let (Some(answer), Some(file_name)) = (answer, example) {
+            if answers.get(day, part, file_name).is_none() {
+                answers.insert(KnownAnswer { day, part, input: file_name.clone(), answer: answer.clone() });
+            }
+            module = module.map(|source| fill_test(&source, day, part, file_name, answer));
+        }
+        if first_example.is_none() {
+            first_example = files.into_iter().next();
+        }
+    }
+
+    answers.save(&answers_path)?;
+    written.push(answers_path);
+    if let Some(module) = module {
+        if fs::read_to_string(&module_path)? != module {
+            fs::write(&module_path, module)
+                .with_context(|| format!("unable to write {}", module_path.display()))?;
+            written.push(module_path);
+        }
+    }
+    Ok(Extracted { written, warnings })
+}
+
+/// Returns a module with a part's example test, as generated by `new`, checking an answer
+fn fill_test(source: &str, day: u8, part: Part, file_name: &str, answer: &Answer) -> String {
+    let generated = format!("\
+    #[test]
+    #[ignore]
+    fn test_part_{0}() {{
+        let input = prepare(\"day{1:02}-example.txt\").unwrap();
+        assert_eq!(part_{0}(&input), Some(1))", part, day);
+    let answer = match answer {
+        Answer::Text(text) => format!("{:?}", text),
+        answer => answer.to_string(),
+    };
+    let filled = format!("\
+    #[test]
+    fn test_part_{0}() {{
+        let input = prepare({1:?}).unwrap();
+        assert_eq!(part_{0}(&input), Some({2}))", part, file_name, answer);
+    source.replace(&generated, &filled)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scaffold;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 13: Example ---</h2><p>For example:</p>
+<pre><code>#.##..##.
+..#.##.#.
+</code></pre>
+<p>Try <code>a &lt; b</code> first.</p>
+<pre><code>1 &amp; <em>2</em>
+</code></pre>
+<p>In this example, the total is <code><em>405</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>123</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now the total is <code><em>400</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse() {
+        let parts = parse(PAGE);
+        assert_eq!(parts, vec![
+            PartPage {
+                examples: vec!["#.##..##.\n..#.##.#.\n".to_string(), "1 & 2\n".to_string()],
+                answer: Some(Answer::Unsigned(405)),
+            },
+            PartPage { examples: vec![], answer: Some(Answer::Unsigned(400)) },
+        ]);
+        assert!(parse("<html></html>").is_empty());
+    }
+
+    #[test]
+    fn test_extract_day() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("src")).unwrap();
+        fs::write(root.path().join("src").join("lib.rs"), "pub mod y2023;\n    Year { year: 2023, days: y2023::DAYS },\n").unwrap();
+        let inputs = tempfile::tempdir().unwrap();
+        let input = inputs.path().join("2024");
+        scaffold::new_day(root.path(), &input, 2024, 13, "Example").unwrap();
+
+        // the answer is for the first example, part 2 has none of its own so its answer is left out
+        let extracted = extract_day(root.path(), &input, 2024, 13, PAGE, false).unwrap();
+        assert_eq!(extracted.warnings.len(), 2);
+        assert!(extracted.warnings[0].contains("part 1 has 2 code blocks"));
+        assert!(extracted.warnings[1].contains("part 2 has no example of its own"));
+        assert_eq!(fs::read_to_string(input.join("day13-example.txt")).unwrap(), "#.##..##.\n..#.##.#.\n");
+        assert_eq!(fs::read_to_string(input.join("day13-example-2.txt")).unwrap(), "1 & 2\n");
+        let answers = Answers::from_path(&input.join(ANSWERS_FILE)).unwrap();
+        assert_eq!(answers.get(13, Part::One, "day13-example.txt"), Some(&Answer::Unsigned(405)));
+        assert_eq!(answers.get(13, Part::One, "day13-example-2.txt"), None);
+        assert_eq!(answers.iter().filter(|known| known.part == Part::Two).count(), 0);
+        let module = fs::read_to_string(root.path().join("src").join("y2024").join("day13.rs")).unwrap();
+        assert!(module.contains("assert_eq!(part_1(&input), Some(405))"));
+        assert!(module.contains("#[ignore]"));
+
+        // part 2 reuses part 1's first example when asked to
+        let extracted = extract_day(root.path(), &input, 2024, 13, PAGE, true).unwrap();
+        assert_eq!(extracted.warnings.len(), 1);
+        let answers = Answers::from_path(&input.join(ANSWERS_FILE)).unwrap();
+        assert_eq!(answers.get(13, Part::Two, "day13-example.txt"), Some(&Answer::Unsigned(400)));
+        let module = fs::read_to_string(root.path().join("src").join("y2024").join("day13.rs")).unwrap();
+        assert!(!module.contains("#[ignore]"));
+        assert!(!module.contains("day13-example-2.txt"));
+        assert!(module.contains("assert_eq!(part_2(&input), Some(400))"));
+
+        // edited examples and answers are kept
+        fs::write(input.join("day13-example.txt"), "edited\n").unwrap();
+        extract_day(root.path(), &input, 2024, 13, &PAGE.replace("405", "1"), false).unwrap();
+        assert_eq!(fs::read_to_string(input.join("day13-example.txt")).unwrap(), "edited\n");
+        let answers = Answers::from_path(&input.join(ANSWERS_FILE)).unwrap();
+        assert_eq!(answers.get(13, Part::One, "day13-example.txt"), Some(&Answer::Unsigned(405)));
+    }
+}
@@ -0,0 +1,174 @@
+use proptest::prelude::*;
+use crate::solution::{Part, Puzzle};
+use crate::{example_files, find_puzzle, puzzles, read_year_input};
+
+/// A small change to a puzzle input, positions wrap around its length
+#[derive(Clone, Debug)]
+pub enum Edit {
+    Replace(usize, char),
+    Insert(usize, char),
+    Remove(usize),
+    DropLine(usize),
+    RepeatLine(usize),
+    Truncate(usize),
+    /// Replaces a number, counted from the start, with another number token
+    Number(usize, &'static str),
+}
+
+/// Characters that mean something to at least one day, and a few that mean nothing to any
+fn puzzle_char() -> impl Strategy<Value = char> {
+    prop::sample::select(vec![
+        '0', '1', '7', '9', '-', '#', '.', '?', 'O', 'S', '|', 'F', 'J', 'L', 'R', 'A', 'Z', 'K',
+        'T', 'a', 'e', 'x', ' ', ':', ';', ',', '=', '(', ')', '\n', 'é',
+    ])
+}
+
+/// Numbers at and beyond the limits of the integer types days parse into
+fn extreme_number() -> impl Strategy<Value = &'static str> {
+    prop::sample::select(vec![
+        "18446744073709551615", "18446744073709551616", "9223372036854775807",
+        "-9223372036854775808", "-9223372036854775809", "4294967295", "4000000000", "-1", "0",
+    ])
+}
+
+/// Replaces one of the numbers of an input with an extreme one
+pub fn number_edit() -> impl Strategy<Value = Edit> {
+    (any::<usize>(), extreme_number()).prop_map(|(at, token)| Edit::Number(at, token))
+}
+
+pub fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        (any::<usize>(), puzzle_char()).prop_map(|(at, c)| Edit::Replace(at, c)),
+        (any::<usize>(), puzzle_char()).prop_map(|(at, c)| Edit::Insert(at, c)),
+        any::<usize>().prop_map(Edit::Remove),
+        any::<usize>().prop_map(Edit::DropLine),
+        any::<usize>().prop_map(Edit::RepeatLine),
+        any::<usize>().prop_map(Edit::Truncate),
+        number_edit(),
+    ]
+}
+
+/// Returns the input with every edit applied in turn
+pub fn apply(input: &str, edits: &[Edit]) -> String {
+    let mut chars:Vec<char> = input.chars().collect();
+    for edit in edits.iter() {
+        let at = |i: usize| i % chars.len().max(1);
+        match *edit {
+            Edit::Replace(i, c) if !chars.is_empty() => {
+                let i = at(i);
+                chars[i] = c;
+            },
+            Edit::Insert(i, c) => {
+                let i = i % (chars.len() + 1);
+                chars.insert(i, c);
+            },
+            Edit::Remove(i) if !chars.is_empty() => {
+                let i = at(i);
+                chars.remove(i);
+            },
+            Edit::Truncate(i) => chars.truncate(i % (chars.len() + 1)),
+            Edit::DropLine(i) | Edit::RepeatLine(i) => {
+                let text:String = chars.iter().collect();
+                let mut lines:Vec<&str> = text.lines().collect();
+                if !lines.is_empty() {
+                    let i = i % lines.len();
+                    match edit {
+                        Edit::DropLine(_) => { lines.remove(i); },
+                        _ => lines.insert(i, lines[i]),
+                    }
+                }
+                chars = (lines.join("\n") + "\n").chars().collect();
+            },
+            Edit::Number(i, token) => {
+                let numbers = numbers(&chars);
+                if !numbers.is_empty() {
+                    let (start, end) = numbers[i % numbers.len()];
+                    chars.splice(start..end, token.chars());
+                }
+            },
+            _ => {},
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Returns the start and end of every run of digits, with a leading `-`
+fn numbers(chars: &[char]) -> Vec<(usize, usize)> {
+    let mut numbers = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_ascii_digit() {
+            let start = if i > 0 && chars[i - 1] == '-' { i - 1 } else { i };
+            while i < chars.len() && chars[i].is_ascii_digit() { i += 1; }
+            numbers.push((start, i));
+        } else {
+            i += 1;
+        }
+    }
+    numbers
+}
+
+/// Parses the input and, if it parses, solves both parts, which must not panic
+pub fn solve(puzzle: &dyn Puzzle, input: &str) {
+    if let Ok(input) = puzzle.parse(input) {
+        for part in Part::ALL {
+            puzzle.solve(part, input.as_ref());
+        }
+    }
+}
+
+/// Every example of every day, as (year, day, text)
+fn examples() -> Vec<(u16, u8, String)> {
+    puzzles()
+        .flat_map(|puzzle| example_files(puzzle.year(), puzzle.day())
+            .into_iter()
+            .filter_map(move |file_name| read_year_input(puzzle.year(), &file_name).ok())
+            .map(move |text| (puzzle.year(), puzzle.day(), text)))
+        .collect()
+}
+
+/// 1024 cases, unless `PROPTEST_CASES` asks for another number
+fn config() -> ProptestConfig {
+    let config = ProptestConfig::default();
+    if std::env::var_os("PROPTEST_CASES").is_some() {
+        config
+    } else {
+        ProptestConfig { cases: 1024, ..config }
+    }
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn test_edited_examples(example in prop::sample::select(examples()), edits in prop::collection::vec(edit(), 1..4)) {
+        let (year, day, text) = example;
+        solve(find_puzzle(year, day).unwrap(), &apply(&text, &edits));
+    }
+
+    #[test]
+    fn test_extreme_numbers(example in prop::sample::select(examples()), edits in prop::collection::vec(number_edit(), 1..4)) {
+        let (year, day, text) = example;
+        solve(find_puzzle(year, day).unwrap(), &apply(&text, &edits));
+    }
+
+    #[test]
+    fn test_any_text(text in "\\PC{0,80}(\n\\PC{0,80}){0,4}") {
+        for puzzle in puzzles() {
+            solve(puzzle, &text);
+        }
+    }
+}
+
+#[test]
+fn test_apply() {
+    assert_eq!(apply("ab\ncd\n", &[Edit::Replace(1, 'x')]), "ax\ncd\n");
+    assert_eq!(apply("ab\ncd\n", &[Edit::Insert(6, 'x')]), "ab\ncd\nx");
+    assert_eq!(apply("ab\ncd\n", &[Edit::DropLine(3)]), "ab\n");
+    assert_eq!(apply("ab\ncd\n", &[Edit::RepeatLine(1)]), "ab\ncd\ncd\n");
+    assert_eq!(apply("ab\ncd\n", &[Edit::Truncate(4), Edit::Remove(0)]), "b\nc");
+    assert_eq!(apply("", &[Edit::Remove(3), Edit::DropLine(1)]), "\n");
+    assert_eq!(apply("a: 12 -3\n", &[Edit::Number(1, "4294967295")]), "a: 12 4294967295\n");
+    assert_eq!(apply("a: 12 -3\n", &[Edit::Number(2, "0")]), "a: 0 -3\n");
+    assert_eq!(apply("abc\n", &[Edit::Number(0, "0")]), "abc\n");
+}
@@ -2,31 +2,166 @@
 
 use std::env;
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 pub mod util;
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day14;
-pub mod day15;
-
-pub fn read_input(file_name: &str) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("input").join(file_name);
-    fs::read_to_string(filepath).expect("Unable to open input file")
-}
-
-pub fn read_input_lines(file_name: &str) -> Vec<String> {
-    read_input(file_name).lines().map(String::from).collect()
+pub mod answer;
+pub mod error;
+pub mod solution;
+pub mod runner;
+pub mod report;
+pub mod answers;
+pub mod scaffold;
+pub mod extract;
+pub mod config;
+pub mod client;
+pub mod submit;
+pub mod watch;
+pub mod perf;
+pub mod metrics;
+pub mod calendar;
+pub mod dashboard;
+#[cfg(test)]
+mod fuzz;
+pub mod y2023;
+
+use error::InputError;
+use solution::Puzzle;
+
+/// The solutions to one year's puzzles
+pub struct Year {
+    pub year: u16,
+    /// Every implemented day, in order
+    pub days: &'static [&'static dyn Puzzle],
+}
+
+/// Every year with solutions, in order
+pub static YEARS: &[Year] = &[
+    Year { year: 2023, days: y2023::DAYS },
+];
+
+/// Returns the latest year with solutions, used when a year isn't given
+pub fn latest_year() -> u16 {
+    YEARS[YEARS.len() - 1].year
+}
+
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// Returns the registered day of a year, if it has been implemented
+pub fn find_puzzle(year: u16, day: u8) -> Option<&'static dyn Puzzle> {
+    find_year(year)?.days.iter().find(|d| d.day() == day).copied()
+}
+
+/// Every implemented day of every year, in order
+pub fn puzzles() -> impl Iterator<Item = &'static dyn Puzzle> {
+    YEARS.iter().flat_map(|year| year.days.iter().copied())
+}
+
+/// Input directory given on the command line, which takes precedence over everything else
+static INPUT_DIR_FLAG: OnceLock<PathBuf> = OnceLock::new();
+/// The input directory, once it has been found
+static INPUT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Sets the input directory given on the command line
+/// Only has an effect before the input directory is first used
+pub fn set_input_dir(path: &Path) {
+    let _ = INPUT_DIR_FLAG.set(path.to_path_buf());
+}
+
+/// Returns the input directory: the `--input-dir` flag or `AOC_INPUT_DIR`, which must exist if given,
+/// otherwise the first that exists of `input_dir` in the config file and `input/` in the crate.
+/// A config file that can't be loaded is an error rather than skipped
+pub fn input_dir() -> Result<PathBuf, InputError> {
+    if let Some(dir) = INPUT_DIR.get() {
+        return Ok(dir.clone());
+    }
+    let explicit = INPUT_DIR_FLAG
+        .get()
+        .map(|dir| ("--input-dir", dir.clone()))
+        .or_else(|| env::var_os(config::INPUT_DIR_VAR).map(|dir| (config::INPUT_DIR_VAR, PathBuf::from(dir))));
+    let dir = match explicit {
+        Some((given_by, dir)) => explicit_dir(given_by, dir)?,
+        None => configured_dir(config::Config::load())?,
+    };
+    Ok(INPUT_DIR.get_or_init(|| dir).clone())
+}
+
+/// Returns a directory the user asked for, which is an error if it doesn't exist
+fn explicit_dir(given_by: &str, dir: PathBuf) -> Result<PathBuf, InputError> {
+    if dir.is_dir() {
+        Ok(dir)
+    } else {
+        Err(InputError::MissingInputDir { path: dir, given_by: given_by.to_string() })
+    }
+}
+
+/// Returns the first that exists of `input_dir` in the loaded config and `input/` in the crate
+fn configured_dir(config: anyhow::Result<config::Config>) -> Result<PathBuf, InputError> {
+    let candidates:Vec<PathBuf> = [
+        config.map_err(InputError::Config)?.input_dir,
+        Some(Path::new(env!("CARGO_MANIFEST_DIR")).join("input")),
+    ]
+        .into_iter()
+        .flatten()
+        .collect();
+    first_dir(candidates)
+}
+
+fn first_dir(candidates: Vec<PathBuf>) -> Result<PathBuf, InputError> {
+    match candidates.iter().find(|dir| dir.is_dir()) {
+        Some(dir) => Ok(dir.clone()),
+        None => Err(InputError::NoInputDir(candidates)),
+    }
+}
+
+/// Returns a year's directory in the input directory, e.g. `input/2023`,
+/// which holds its inputs, known answers, submissions and timings
+pub fn year_dir(year: u16) -> Result<PathBuf, InputError> {
+    Ok(input_dir()?.join(year.to_string()))
+}
+
+/// Returns the path of a file in a year's directory
+pub fn year_path(year: u16, file_name: &str) -> Result<PathBuf, InputError> {
+    Ok(year_dir(year)?.join(file_name))
+}
+
+/// Returns the names of a day's example input files in its year's directory, in order
+pub fn example_files(year: u16, day: u8) -> Vec<String> {
+    let prefix = format!("day{:02}-example", day);
+    let mut files:Vec<String> = year_dir(year)
+        .ok()
+        .and_then(|dir| fs::read_dir(dir).ok())
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+                .collect()
+        })
+        .unwrap_or_default();
+    // "day10-example.txt" before "day10-example-2.txt" before "day10-example-10.txt"
+    files.sort_by_key(|name| (name.len(), name.clone()));
+    files
+}
+
+/// Reads a file from a year's directory
+pub fn read_year_input(year: u16, file_name: &str) -> Result<String, InputError> {
+    read_path(year_path(year, file_name)?)
+}
+
+fn read_path(filepath: PathBuf) -> Result<String, InputError> {
+    let bytes = fs::read(&filepath).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(filepath.clone()),
+        _ => InputError::Unreadable { path: filepath.clone(), source },
+    })?;
+    if bytes.is_empty() {
+        return Err(InputError::Empty(filepath));
+    }
+    String::from_utf8(bytes).map_err(|_| InputError::InvalidUtf8(filepath))
 }
 
 #[cfg(test)]
@@ -34,14 +169,76 @@ mod test {
     use super::*;
 
     #[test]
-    fn test_read_input() {
-        let input = read_input("test.txt");
-        assert_eq!(input.lines().next().unwrap(), "I am a test")
+    fn test_read_path_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("empty.txt"), "").unwrap();
+        fs::write(dir.path().join("invalid.txt"), [0xff, 0xfe]).unwrap();
+        assert!(matches!(read_path(dir.path().join("missing.txt")), Err(InputError::NotFound(_))));
+        assert!(matches!(read_path(dir.path().join("empty.txt")), Err(InputError::Empty(_))));
+        assert!(matches!(read_path(dir.path().join("invalid.txt")), Err(InputError::InvalidUtf8(_))));
+    }
+
+    #[test]
+    fn test_first_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing");
+        assert_eq!(first_dir(vec![missing.clone(), dir.path().to_path_buf()]).unwrap(), dir.path());
+        let error = first_dir(vec![missing.clone(), dir.path().join("input")]).unwrap_err();
+        assert!(matches!(&error, InputError::NoInputDir(tried) if tried.len() == 2));
+        assert!(error.to_string().contains(&missing.display().to_string()));
+    }
+
+    #[test]
+    fn test_configured_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(config::CONFIG_FILE);
+        fs::write(&path, "input_dir = \".\"\n").unwrap();
+        assert_eq!(configured_dir(config::Config::from_path(&path)).unwrap(), dir.path().join("."));
+        fs::write(&path, "input_dir = \"missing\"\n").unwrap();
+        assert!(configured_dir(config::Config::from_path(&path)).unwrap().ends_with("input"));
+        fs::write(&path, "input_dir = ").unwrap();
+        let error = configured_dir(config::Config::from_path(&path)).unwrap_err();
+        assert!(matches!(error, InputError::Config(_)));
+        assert!(error.to_string().contains(&path.display().to_string()));
+        let error = configured_dir(config::Config::from_path(&dir.path().join("missing.toml"))).unwrap_err();
+        assert!(error.to_string().contains("missing.toml"));
+    }
+
+    #[test]
+    fn test_explicit_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(explicit_dir("--input-dir", dir.path().to_path_buf()).unwrap(), dir.path());
+        let missing = dir.path().join("missing");
+        let error = explicit_dir("--input-dir", missing.clone()).unwrap_err();
+        assert!(matches!(&error, InputError::MissingInputDir { path, .. } if *path == missing));
+        assert_eq!(error.to_string(), format!("input directory {} given by --input-dir does not exist", missing.display()));
+    }
+
+    #[test]
+    fn test_example_files() {
+        assert_eq!(example_files(2023, 10), vec![
+            "day10-example.txt",
+            "day10-example-2.txt",
+            "day10-example-3.txt",
+            "day10-example-4.txt",
+        ]);
+        assert!(example_files(2023, 13).is_empty());
+        assert!(example_files(2015, 1).is_empty());
+    }
+
+    #[test]
+    fn test_read_year_input() {
+        assert!(read_year_input(2023, "day02-example.txt").unwrap().starts_with("Game 1:"));
+        let error = read_year_input(2023, "missing.txt").unwrap_err();
+        assert!(error.path().unwrap().ends_with("input/2023/missing.txt"));
     }
 
     #[test]
-    fn test_read_input_lines() {
-        let input = read_input_lines("test.txt");
-        assert_eq!(input[0], "I am a test")
+    fn test_find_puzzle() {
+        assert_eq!(find_puzzle(2023, 5).unwrap().title(), "If You Give A Seed A Fertilizer");
+        assert!(find_puzzle(2023, 13).is_none());
+        assert!(find_puzzle(2015, 1).is_none());
+        assert_eq!(latest_year(), 2023);
+        assert_eq!(puzzles().count(), YEARS.iter().map(|y| y.days.len()).sum::<usize>());
     }
 }
\ No newline at end of file
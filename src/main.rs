@@ -1,128 +1,644 @@
-use std::time::SystemTime;
+use std::io;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use colored::Colorize;
-use adventofcode_2023::*;
-
-// 🎁 == pending
-// 🌟 == complete
-// ❄️ == incomplete
+use adventofcode::answer::Answer;
+use adventofcode::answers::{Answers, KnownAnswer, Verification, ANSWERS_FILE};
+use adventofcode::client::{self, Client, Fetched};
+use adventofcode::config::Config;
+use adventofcode::{find_puzzle, latest_year, set_input_dir, year_dir, year_path};
+use adventofcode::report::{timing_table, Record, RecordWriter, Status};
+use adventofcode::runner::{self, Days, InputSource, Job, PuzzleId, Selection};
+use adventofcode::{extract, scaffold};
+use adventofcode::solution::Part;
+use adventofcode::submit::{self, Outcome, Submissions, SUBMISSIONS_FILE};
+use adventofcode::watch::{self, Change};
+use adventofcode::perf::{self, History, Timing, PERF_FILE};
+use adventofcode::calendar::{self, legend};
+use adventofcode::dashboard;
+use adventofcode::metrics;
+use anyhow::Context;
 
-fn main() {
-    println!("{}", "\n\n🎄🎄🎄🎄 Advent of Code 2022 🎄🎄🎄🎄".bright_red());
-
-    println!("{} {} {}", "----------".red(), "Day  1".bright_green(), "----------".red());
-    println!("\t🎁 Trebuchet?!");
-    if let Ok(input) = day01::prepare("day01.txt") {
-        let part1 = day01::part_1(&input);
-        let part2 = day01::part_2(&input);
-        println!("🌟 {}", part1.unwrap());
-        println!("🌟 {}", part2.unwrap());
-    }
-
-    println!("{} {} {}", "----------".red(), "Day  2".bright_green(), "----------".red());
-    println!("\t🎁 Cube Conundrum");
-    if let Ok(input) = day02::prepare("day02.txt") {
-        let part1 = day02::part_1(&input);
-        let part2 = day02::part_2(&input);
-        println!("🌟 {}", part1.unwrap());
-        println!("🌟 {}", part2.unwrap());
-    }
-
-    println!("{} {} {}", "----------".red(), "Day  3".bright_green(), "----------".red());
-    println!("\t🎁 Gear Ratios");
-    if let Ok(input) = day03::prepare("day03.txt") {
-        let part1 = day03::part_1(&input);
-        let part2 = day03::part_2(&input);
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}", part2.unwrap_or(0));
-    }
-
-    println!("{} {} {}", "----------".red(), "Day  4".bright_green(), "----------".red());
-    println!("\t🎁 Scratchcards");
-    if let Ok(input) = day04::prepare("day04.txt") {
-        let part1 = day04::part_1(&input);
-        let part2 = day04::part_2(&input);
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}", part2.unwrap_or(0));
-    }
-
-    println!("{} {} {}", "----------".red(), "Day  5".bright_green(), "----------".red());
-    println!("\t🎁 If You Give a Seed a Fertilizer");
-    if let Ok(input) = day05::prepare("day05.txt") {
-        let part1 = day05::part_1(&input);
-
-        let start = SystemTime::now();
-        let part2 = day05::part_2(&input);
-        let end = SystemTime::now();
-        let duration = end.duration_since(start).unwrap();
-
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}, {}", part2.unwrap_or(0), format!("{}µ", duration.as_micros()).bright_green());
-    }
-
-    println!("{} {} {}", "----------".red(), "Day  6".bright_green(), "----------".red());
-    println!("\t🎁 Wait For It!");
-    if let Ok(input) = day06::prepare_1("day06.txt") {
-        let part1 = day06::part_1(&input);
-        println!("🌟 {}", part1.unwrap_or(0));
-    }
-    if let Ok(input) = day06::prepare_2("day06.txt") {
-        let part2 = day06::part_2(&input);
-        println!("🌟 {}", part2.unwrap_or(0));
-    }
-
-    println!("{} {} {}", "----------".red(), "Day  7".bright_green(), "----------".red());
-    println!("\t🎁 Camel Cards");
-    if let Ok(mut input) = day07::prepare("day07.txt") {
-        let part1 = day07::part_1(&mut input);
-        let part2 = day07::part_2(&mut input);
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}", part2.unwrap_or(0));
-    }
-
-    println!("{} {} {}", "----------".red(), "Day  8".bright_green(), "----------".red());
-    println!("\t🎁 Haunted Wasteland");
-    if let Ok(input) = day08::prepare("day08.txt") {
-        let part1 = day08::part_1(&input);
-        let part2 = day08::part_2(&input);
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}", part2.unwrap_or(0));
-    }
-
-    println!("{} {} {}", "----------".red(), "Day  9".bright_green(), "----------".red());
-    println!("\t🎁 Mirage Maintenance");
-    if let Ok(input) = day09::prepare("day09.txt") {
-        let part1 = day09::part_1(&input);
-        let part2 = day09::part_2(&input);
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}", part2.unwrap_or(0));
-    }
-
-    println!("{} {} {}", "----------".red(), "Day 10".bright_green(), "----------".red());
-    println!("\t🎁 Mirage Maintenance");
-    if let Ok(input) = day10::prepare("day10.txt") {
-        let part1 = day10::part_1(&input);
-        let part2 = day10::part_2(&input);
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}", part2.unwrap_or(0));
-    }
-
-    println!("{} {} {}", "----------".red(), "Day 11".bright_green(), "----------".red());
-    println!("\t🎁 Mirage Maintenance");
-    if let Ok(input) = day11::prepare("day11.txt") {
-        let part1 = day11::part_1(&input);
-        let part2 = day11::part_2(&input);
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}", part2.unwrap_or(0));
-    }
+/// Exit status when a selected day has not been implemented
+const EXIT_MISSING_DAY: u8 = 2;
+/// Exit status when a day's input could not be read or prepared
+const EXIT_BAD_INPUT: u8 = 3;
+/// Exit status when `--check` finds an answer that differs from the known answer
+const EXIT_MISMATCH: u8 = 4;
 
-    println!("{} {} {}", "----------".red(), "Day 12".bright_green(), "----------".red());
-    println!("\t🎁 Hot Springs");
-    if let Ok(input) = day12::prepare("day12.txt") {
-        let part1 = day12::part_1(&input);
-        let part2 = day12::part_2(&input);
-        println!("🌟 {}", part1.unwrap_or(0));
-        println!("🌟 {}", part2.unwrap_or(0));
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions", after_help = "\
+Exit status:
+  0  every selected day ran
+  2  a selected day has not been implemented, or the arguments are invalid
+  3  an input file could not be read
+  4  with --check, an answer did not match its known answer")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Directory of puzzle inputs, instead of AOC_INPUT_DIR, input_dir in aoc.toml or input/
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+
+    /// Trace parsing and solving on stderr: -v for info, -vv for debug, -vvv for trace, or set AOC_LOG
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions against their inputs
+    Run(RunArgs),
+    /// Generate and register a new day from src/template.rs
+    New(NewArgs),
+    /// Download puzzle inputs that have not been downloaded yet
+    Fetch(FetchArgs),
+    /// Submit the answer to one part of a day's puzzle input
+    Submit(SubmitArgs),
+    /// Seed a day's examples, known answers and example tests from its saved puzzle page
+    Extract(ExtractArgs),
+    /// Re-run a day on its examples and puzzle input whenever they change
+    Watch(WatchArgs),
+    /// Record timings of every day, and compare them with earlier timings
+    #[command(subcommand)]
+    Perf(PerfCommand),
+    /// Show a year's season as a calendar, and run and visualize days from it
+    Dashboard(DashboardArgs),
+}
+
+#[derive(Subcommand)]
+enum PerfCommand {
+    /// Time days on their puzzle inputs and add the timings to the year's perf.csv
+    Record(PerfRecordArgs),
+    /// Compare the latest timings with a baseline, exiting with an error if any got slower
+    Compare(PerfCompareArgs),
+}
+
+#[derive(Args)]
+struct PerfRecordArgs {
+    /// Days to time: a day (5), a range (3..8, 3..=8) or "all", of the latest year or e.g. 2023/5
+    #[arg(default_value = "all")]
+    days: Selection,
+
+    /// Times to run each stage, the median is recorded
+    #[arg(short = 'n', long, default_value_t = 5)]
+    samples: u32,
+}
+
+#[derive(Args)]
+struct PerfCompareArgs {
+    /// Percentage slowdown that counts as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Commit to compare against, instead of the latest run of an earlier commit
+    #[arg(short, long)]
+    baseline: Option<String>,
+
+    /// Year whose timings to compare, instead of the latest year
+    #[arg(short, long)]
+    year: Option<u16>,
+}
+
+#[derive(Args)]
+struct DashboardArgs {
+    /// Year to show, instead of the latest year
+    year: Option<u16>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Days to run: a day (5), a range (3..8, 3..=8) or "all", of the latest year or e.g. 2023/5
+    #[arg(default_value = "all")]
+    days: Selection,
+
+    /// Only run one part
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Input file name in the year's input directory, instead of dayNN.txt, or - for stdin
+    #[arg(short, long, conflicts_with = "example")]
+    input: Option<String>,
+
+    /// Run against every dayNN-example*.txt file instead of dayNN.txt
+    #[arg(short, long)]
+    example: bool,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+
+    /// Exit with an error if any answer differs from the year's answers.csv
+    #[arg(short, long)]
+    check: bool,
+
+    /// Number of days to run at once, 0 for one per CPU
+    #[arg(short = 'j', long, default_value_t = 0)]
+    threads: usize,
+
+    /// Print the work counted while solving each part, e.g. memo hits or steps walked
+    #[arg(short, long)]
+    metrics: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to generate, of the latest year or e.g. 2024/1
+    day: PuzzleId,
+
+    /// Title of the puzzle
+    #[arg(short, long, default_value = "")]
+    title: String,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Days to download: a day (5), a range (3..8, 3..=8) or "all" implemented days,
+    /// of the latest year or e.g. 2024/1..=25
+    days: Selection,
+}
+
+#[derive(Args)]
+struct ExtractArgs {
+    /// Day the page is for, of the latest year or e.g. 2024/1
+    day: PuzzleId,
+
+    /// The puzzle page, saved from the browser as HTML
+    page: PathBuf,
+
+    /// Take part two's answer as the answer for part one's first example, when part two has no example
+    #[arg(long)]
+    reuse_example: bool,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to watch, of the latest year or e.g. 2023/5
+    day: PuzzleId,
+
+    /// Also watch a source directory, e.g. src, and re-run with cargo so changes are compiled in
+    #[arg(short, long)]
+    src: Option<PathBuf>,
+
+    /// How often to check for changes, in milliseconds
+    #[arg(short, long, default_value_t = 500)]
+    interval: u64,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit, of the latest year or e.g. 2023/5
+    day: PuzzleId,
+
+    /// Part to submit
+    part: Part,
+
+    /// Wait out any delay the site asks for, instead of giving up
+    #[arg(short, long)]
+    wait: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Colored text for reading in a terminal
+    Human,
+    /// One JSON object per day and part, per line
+    Json,
+    /// One CSV row per day and part, with a header row
+    Csv,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    metrics::init_tracing(cli.verbose);
+    if let Some(input_dir) = cli.input_dir.as_ref() {
+        set_input_dir(input_dir);
+    }
+    let command = cli.command.unwrap_or(Command::Run(RunArgs {
+        days: Selection { year: latest_year(), days: Days::All },
+        part: None,
+        input: None,
+        example: false,
+        format: Format::Human,
+        check: false,
+        threads: 0,
+        metrics: false,
+    }));
+    match command {
+        Command::Run(args) => run(args),
+        Command::New(args) => new(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Extract(args) => extract(args),
+        Command::Perf(command) => perf(command).unwrap_or_else(|error| {
+            eprintln!("{} {:#}", "error:".bright_red(), error);
+            ExitCode::FAILURE
+        }),
+        Command::Dashboard(args) => dashboard::run(args.year.unwrap_or_else(latest_year)).map(|_| ExitCode::SUCCESS).unwrap_or_else(|error| {
+            eprintln!("{} {:#}", "error:".bright_red(), error);
+            ExitCode::FAILURE
+        }),
+        Command::Watch(args) => watch(args).unwrap_or_else(|error| {
+            eprintln!("{} {:#}", "error:".bright_red(), error);
+            ExitCode::FAILURE
+        }),
+        Command::Submit(args) => submit(args).unwrap_or_else(|error| {
+            eprintln!("{} {:#}", "error:".bright_red(), error);
+            ExitCode::FAILURE
+        }),
+    }
+}
+
+fn perf(command: PerfCommand) -> anyhow::Result<ExitCode> {
+    match command {
+        PerfCommand::Record(args) => {
+            let path = year_path(args.days.year, PERF_FILE)?;
+            let (days, missing) = args.days.resolve();
+            let mut status = 0;
+            for day in missing.iter() {
+                eprintln!("{} day {} is not implemented", "error:".bright_red(), day);
+                status = EXIT_MISSING_DAY;
+            }
+            let run = perf::new_run();
+            let commit = perf::git_commit();
+            let mut timings = vec![];
+            for puzzle in days.iter() {
+                match perf::measure(*puzzle, &format!("day{:02}.txt", puzzle.day()), args.samples) {
+                    Ok(measured) => {
+                        for (stage, median) in measured {
+                            println!("day {:>2} {:<8}{:>12}", puzzle.day(), stage.to_string(), format!("{:.2?}", median));
+                            timings.push(Timing {
+                                run,
+                                commit: commit.clone(),
+                                day: puzzle.day(),
+                                stage,
+                                median_ns: median.as_nanos() as u64,
+                                samples: args.samples,
+                            });
+                        }
+                    },
+                    Err(error) => {
+                        eprintln!("{} day {}: {:#}", "error:".bright_red(), puzzle.day(), error);
+                        status = status.max(EXIT_BAD_INPUT);
+                    },
+                }
+            }
+            if timings.is_empty() {
+                eprintln!("{} no days were timed, nothing recorded", "error:".bright_red());
+                return Ok(ExitCode::from(status.max(EXIT_MISSING_DAY)));
+            }
+            History::append(&path, &timings)?;
+            println!("{} {} timings for {}", "recorded".bright_green(), timings.len(), commit.as_deref().unwrap_or("no commit"));
+            Ok(ExitCode::from(status))
+        },
+        PerfCommand::Compare(args) => {
+            let path = year_path(args.year.unwrap_or_else(latest_year), PERF_FILE)?;
+            let history = History::load(&path)?;
+            let latest = history.latest().context("no timings have been recorded, run perf record")?;
+            let baseline = history
+                .baseline(latest, args.baseline.as_deref())
+                .context("no earlier timings to compare with")?;
+            let commit = |run| history.run(run)[0].commit.clone().unwrap_or("no commit".to_string());
+            println!("{} → {}", commit(baseline), commit(latest));
+            let comparisons = perf::compare(&history.run(baseline), &history.run(latest), args.threshold);
+            perf::write_comparisons(io::stdout(), &comparisons)?;
+            match comparisons.iter().any(|c| c.regressed) {
+                true => Ok(ExitCode::FAILURE),
+                false => Ok(ExitCode::SUCCESS),
+            }
+        },
+    }
+}
+
+fn watch(args: WatchArgs) -> anyhow::Result<ExitCode> {
+    let PuzzleId { year, day } = args.day;
+    let Some(puzzle) = find_puzzle(year, day) else {
+        eprintln!("{} day {} is not implemented", "error:".bright_red(), args.day);
+        return Ok(ExitCode::from(EXIT_MISSING_DAY));
+    };
+    let mut previous = vec![];
+    let mut last_snapshot = None;
+    let mut runs = 0;
+    loop {
+        let snapshot = watch::snapshot(&watch::watched_files(year, day, args.src.as_deref())?);
+        if last_snapshot.as_ref() != Some(&snapshot) {
+            last_snapshot = Some(snapshot);
+            runs += 1;
+            let header = format!("Day {:>2} run {}", day, runs);
+            println!("{} {} {}", "----------".red(), header.bright_green(), "----------".red());
+            let answers = Answers::load(year)?;
+            let records = match args.src {
+                Some(_) => watch::run_cargo(args.day, &answers),
+                None => Ok(watch::run_day(puzzle, &answers)),
+            };
+            match records {
+                Ok(records) => {
+                    for (change, record) in watch::diff(&previous, &records) {
+                        print_change(&change, record);
+                    }
+                    previous = records;
+                },
+                Err(error) => eprintln!("{} {:#}", "error:".bright_red(), error),
+            }
+        }
+        thread::sleep(Duration::from_millis(args.interval));
+    }
+}
+
+fn print_change(change: &Change, record: &Record) {
+    let input = format!("{} part {}", record.input, record.part);
+    let answer = match (&record.status, &record.answer) {
+        (Status::Error, _) => "unreadable input".to_string(),
+        (_, Answer::NotImplemented) => "no answer".to_string(),
+        (_, answer) => answer.to_string(),
+    };
+    let change = match change {
+        Change::New | Change::Same => String::new(),
+        Change::Changed { previous } => format!("(was {})", previous).yellow().to_string(),
+    };
+    let verification = match &record.verification {
+        Verification::Verified => "✔".bright_green().to_string(),
+        Verification::Mismatch { expected } => format!("✘ expected {}", expected).bright_red().to_string(),
+        Verification::Unknown => String::new(),
+    };
+    let line = format!("\t{:<28} {} {} {}", input, answer, change, verification);
+    println!("{}", line.trim_end());
+}
+
+fn submit(args: SubmitArgs) -> anyhow::Result<ExitCode> {
+    let PuzzleId { year, day } = args.day;
+    let Some(puzzle) = find_puzzle(year, day) else {
+        eprintln!("{} day {} is not implemented", "error:".bright_red(), args.day);
+        return Ok(ExitCode::from(EXIT_MISSING_DAY));
+    };
+    let file_name = format!("day{:02}.txt", day);
+    let run = runner::run(puzzle, &file_name, &[args.part])?;
+    let answer = run.parts[0].answer.clone();
+    if !answer.is_implemented() {
+        anyhow::bail!("day {} part {} has no answer", args.day, args.part);
+    }
+    let client = Client::from_config(&Config::load()?, year)?;
+    let mut submissions = Submissions::load(&year_path(year, SUBMISSIONS_FILE)?)?;
+
+    println!("submitting {} for day {} part {}", answer.to_string().bright_green(), args.day, args.part);
+    let submission = if args.wait {
+        submissions.submit_waiting(&client, day, args.part, &answer, submit::now, |wait| {
+            println!("waiting {}s before submitting", wait.as_secs());
+            thread::sleep(wait);
+        })?
+    } else {
+        submissions.submit(&client, day, args.part, &answer, submit::now())?
+    };
+
+    let wait = match submission.wait_until {
+        Some(wait_until) => format!(", wait {}s before submitting again", wait_until.saturating_sub(submission.submitted_at)),
+        None => String::new(),
+    };
+    match submission.outcome {
+        Outcome::Correct => {
+            println!("🌟 {}", "correct".bright_green());
+            let path = year_path(year, ANSWERS_FILE)?;
+            let mut answers = Answers::load(year)?;
+            answers.insert(KnownAnswer { day, part: args.part, input: file_name, answer });
+            answers.save(&path)?;
+            Ok(ExitCode::SUCCESS)
+        },
+        outcome => {
+            println!("❄️ {}{}", outcome.to_string().bright_red(), wait);
+            Ok(ExitCode::FAILURE)
+        },
     }
+}
 
-    println!("{}", "============================".bright_red());
+fn fetch(args: FetchArgs) -> ExitCode {
+    let year = args.days.year;
+    let loaded = Config::load().and_then(|config| Ok((config, year_dir(year)?)));
+    let (config, year_dir) = match loaded {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            eprintln!("{} {:#}", "error:".bright_red(), error);
+            return ExitCode::FAILURE;
+        },
+    };
+    let days:Vec<u8> = match args.days.days {
+        Days::All => args.days.resolve().0.iter().map(|d| d.day()).collect(),
+        Days::List(days) => days,
+    };
+    if let Err(error) = fs::create_dir_all(&year_dir) {
+        eprintln!("{} {}: {}", "error:".bright_red(), year_dir.display(), error);
+        return ExitCode::FAILURE;
+    }
+    let mut status = ExitCode::SUCCESS;
+    for day in days {
+        let path = year_dir.join(format!("day{:02}.txt", day));
+        match client::fetch_input(|| Client::from_config(&config, year), day, &path) {
+            Ok(Fetched::Cached) => println!("{} {}", "cached".bright_green(), path.display()),
+            Ok(Fetched::Downloaded) => println!("{} {}", "downloaded".bright_green(), path.display()),
+            Err(error) => {
+                eprintln!("{} day {}: {:#}", "error:".bright_red(), day, error);
+                status = ExitCode::FAILURE;
+            },
+        }
+    }
+    status
+}
+
+fn extract(args: ExtractArgs) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let written = fs::read_to_string(&args.page)
+        .map_err(anyhow::Error::from)
+        .and_then(|html| {
+            let year_dir = year_dir(args.day.year)?;
+            extract::extract_day(root, &year_dir, args.day.year, args.day.day, &html, args.reuse_example)
+        });
+    match written {
+        Ok(extracted) => {
+            for path in extracted.written.iter() {
+                println!("{} {}", "wrote".bright_green(), path.display());
+            }
+            for warning in extracted.warnings.iter() {
+                eprintln!("{} {}", "warning:".bright_yellow(), warning);
+            }
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("{} {}: {:#}", "error:".bright_red(), args.page.display(), error);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn new(args: NewArgs) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let written = year_dir(args.day.year)
+        .map_err(anyhow::Error::from)
+        .and_then(|year_dir| scaffold::new_day(root, &year_dir, args.day.year, args.day.day, &args.title));
+    match written {
+        Ok(paths) => {
+            for path in paths.iter() {
+                println!("{} {}", "wrote".bright_green(), path.display());
+            }
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("{} {:#}", "error:".bright_red(), error);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let (days, missing) = args.days.resolve();
+    let source = match (args.input, args.example) {
+        (Some(file_name), _) if file_name == "-" => InputSource::Stdin,
+        (Some(file_name), _) => InputSource::File(file_name),
+        (None, true) => InputSource::Examples,
+        (None, false) => InputSource::Puzzle,
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let mut writer = match args.format {
+        Format::Human => None,
+        Format::Json => Some(RecordWriter::json(io::stdout())),
+        Format::Csv => Some(RecordWriter::csv(io::stdout())),
+    };
+    let stdin:Option<Arc<str>> = match source {
+        InputSource::Stdin if days.len() + missing.len() != 1 => {
+            eprintln!("{} stdin can only be read for a single day", "error:".bright_red());
+            return ExitCode::from(EXIT_MISSING_DAY);
+        },
+        InputSource::Stdin => match io::read_to_string(io::stdin()) {
+            Ok(input) if !input.trim().is_empty() => Some(input.into()),
+            Ok(_) => {
+                eprintln!("{} stdin is empty", "error:".bright_red());
+                return ExitCode::from(EXIT_BAD_INPUT);
+            },
+            Err(error) => {
+                eprintln!("{} unable to read stdin: {}", "error:".bright_red(), error);
+                return ExitCode::from(EXIT_BAD_INPUT);
+            },
+        },
+        _ => None,
+    };
+    let year = args.days.year;
+    let mut records:Vec<Record> = vec![];
+    let answers = match Answers::load(year) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{} {:#}", "error:".bright_red(), error);
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let season = calendar::calendar(year, &answers);
+
+    let mut status = 0;
+    for day in missing.iter() {
+        eprintln!("{} day {} is not implemented", "error:".bright_red(), day);
+        records.extend(Record::from_missing(year, *day, &parts));
+        status = EXIT_MISSING_DAY;
+    }
+
+    if days.is_empty() && missing.is_empty() {
+        eprintln!("{} no days of {} are implemented", "error:".bright_red(), year);
+        return ExitCode::from(EXIT_MISSING_DAY);
+    }
+
+    if writer.is_none() {
+        println!("{}", format!("\n\n🎄🎄🎄🎄 Advent of Code {} 🎄🎄🎄🎄", year).bright_red());
+    }
+
+    let mut jobs = vec![];
+    for day in days.iter() {
+        let files = source.files(year, day.day());
+        if files.is_empty() {
+            eprintln!("{} day {} has no example input files", "error:".bright_red(), day.day());
+            status = status.max(EXIT_BAD_INPUT);
+        }
+        jobs.extend(files.into_iter().map(|file_name| Job { puzzle: *day, file_name, input: stdin.clone() }));
+    }
+
+    let mut printed_day = None;
+    let ran = runner::run_all(&jobs, &parts, args.threads, |job, result| {
+        let day = job.puzzle;
+        if writer.is_none() && printed_day != Some(day.day()) {
+            let header = format!("Day {:>2}", day.day());
+            println!("{} {} {}", "----------".red(), header.bright_green(), "----------".red());
+            println!("\t{} {}", season[day.day() as usize - 1].state.emoji(), day.title());
+            printed_day = Some(day.day());
+        }
+        if writer.is_none() && source != InputSource::Puzzle {
+            println!("\t📄 {}", job.file_name);
+        }
+        match result {
+            Ok(run) => {
+                let day_records = Record::from_run(year, day.day(), day.title(), &run, &answers);
+                for (record, result) in day_records.iter().zip(run.parts.iter()) {
+                    if writer.is_none() {
+                        print_record(record);
+                        if args.metrics && !result.metrics.is_empty() {
+                            println!("\t📈 part {}  {}", result.part, metrics::summary(&result.metrics));
+                        }
+                    }
+                    if let (true, Some(expected)) = (args.check, record.expected.as_ref()) {
+                        eprintln!("{} day {} part {} on {}: expected {}, got {}",
+                            "mismatch:".bright_red(), record.day, record.part, record.input,
+                            expected, record.answer);
+                        status = status.max(EXIT_MISMATCH);
+                    }
+                }
+                records.extend(day_records);
+            },
+            Err(error) => {
+                eprintln!("{} day {}: {:#}", "error:".bright_red(), day.day(), error);
+                records.extend(Record::from_error(year, day.day(), day.title(), &job.file_name, &parts));
+                status = status.max(EXIT_BAD_INPUT);
+            },
+        }
+    });
+    if let Err(error) = ran {
+        eprintln!("{} {:#}", "error:".bright_red(), error);
+        return ExitCode::FAILURE;
+    }
+
+    match writer.as_mut() {
+        None => {
+            println!("{}", "============================".bright_red());
+            print!("{}", timing_table(&records));
+            println!("{}", legend(&season));
+        },
+        Some(writer) => {
+            records.sort_by_key(|r| r.day);
+            let written = records
+                .iter()
+                .try_for_each(|record| writer.write(record))
+                .and_then(|_| writer.flush());
+            if let Err(error) = written {
+                eprintln!("{} {:#}", "error:".bright_red(), error);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+    ExitCode::from(status)
+}
+
+fn print_record(record: &Record) {
+    let answer = match &record.answer {
+        Answer::NotImplemented => format!("❄️ part {} has no answer", record.part),
+        answer => format!("🌟 {}", answer),
+    };
+    match &record.verification {
+        Verification::Verified => println!("{} {}", answer, "✔".bright_green()),
+        Verification::Mismatch { expected } => {
+            println!("{} {}", answer, format!("✘ expected {}", expected).bright_red())
+        },
+        Verification::Unknown => println!("{}", answer),
+    }
 }
@@ -0,0 +1,76 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable with a tracing filter, e.g. `adventofcode::y2023::day14=trace`,
+/// used instead of the verbosity flag
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// Counts of the work done by a solution, by name
+pub type Metrics = BTreeMap<&'static str, u64>;
+
+thread_local! {
+    static COUNTERS: RefCell<Metrics> = const { RefCell::new(Metrics::new()) };
+}
+
+/// Adds `n` to a counter of the current thread
+pub fn count(name: &'static str, n: u64) {
+    COUNTERS.with(|counters| *counters.borrow_mut().entry(name).or_insert(0) += n);
+}
+
+/// Returns a counter of the current thread
+pub fn get(name: &'static str) -> u64 {
+    COUNTERS.with(|counters| counters.borrow().get(name).copied().unwrap_or(0))
+}
+
+/// Returns and resets every counter of the current thread
+pub fn take() -> Metrics {
+    COUNTERS.with(|counters| counters.take())
+}
+
+/// Formats metrics as `name value` pairs, e.g. `memo_hits 12  memo_misses 40`
+pub fn summary(metrics: &Metrics) -> String {
+    metrics
+        .iter()
+        .map(|(name, value)| format!("{} {}", name, value))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+/// Sends tracing output to stderr: warnings by default, then info, debug and trace
+/// with each extra `verbosity`, unless `AOC_LOG` has a filter
+pub fn init_tracing(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .with_env_var(LOG_VAR)
+        .from_env_lossy();
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        take();
+        count("steps", 2);
+        count("steps", 3);
+        count("hits", 1);
+        assert_eq!(get("steps"), 5);
+        assert_eq!(get("misses"), 0);
+        let metrics = take();
+        assert_eq!(summary(&metrics), "hits 1  steps 5");
+        assert!(take().is_empty());
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use crate::read_year_input;
+use crate::solution::{Part, Puzzle};
+
+/// Name of the timing history in each year's directory
+pub const PERF_FILE: &str = "perf.csv";
+
+/// The timed stages of a day
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+}
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// The median time of one stage of a day, from one timing run
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Timing {
+    /// When the timing run started, in milliseconds since the epoch, shared by every timing of the run
+    pub run: u64,
+    /// The git commit that was timed, suffixed with `-dirty` if there were uncommitted changes
+    pub commit: Option<String>,
+    pub day: u8,
+    pub stage: Stage,
+    pub median_ns: u64,
+    pub samples: u32,
+}
+
+/// Times parsing a day's puzzle input and solving both parts, `samples` times each
+pub fn measure(puzzle: &dyn Puzzle, file_name: &str, samples: u32) -> Result<Vec<(Stage, Duration)>> {
+    let text = read_year_input(puzzle.year(), file_name)?;
+    let mut input = puzzle.parse(&text)?;
+    let mut timings = vec![(Stage::Parse, median(samples, || {
+        input = puzzle.parse(&text).expect("input parsed once already");
+    }))];
+    for (stage, part) in [(Stage::Part1, Part::One), (Stage::Part2, Part::Two)] {
+        timings.push((stage, median(samples, || {
+            puzzle.solve(part, input.as_ref());
+        })));
+    }
+    Ok(timings)
+}
+
+fn median(samples: u32, mut f: impl FnMut()) -> Duration {
+    let mut times:Vec<Duration> = (0..samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    times[times.len() / 2]
+}
+
+/// Returns an id for a new timing run, the current time in milliseconds since the epoch
+pub fn new_run() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Returns the current git commit, if the crate is in a git checkout
+pub fn git_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => Some(format!("{}-dirty", commit)),
+        _ => Some(commit),
+    }
+}
+
+/// Every timing recorded so far, in the order they were recorded
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    timings: Vec<Timing>,
+}
+impl History {
+    /// Loads the timing history, a missing file has no timings
+    pub fn load(path: &Path) -> Result<History> {
+        let mut history = History::default();
+        if path.exists() {
+            let mut reader = csv::Reader::from_path(path)
+                .with_context(|| format!("unable to read timing history {}", path.display()))?;
+            for row in reader.deserialize() {
+                history.timings.push(row.with_context(|| format!("invalid timing history {}", path.display()))?);
+            }
+        }
+        Ok(history)
+    }
+
+    /// Appends the timings of a run to the history file
+    pub fn append(path: &Path, timings: &[Timing]) -> Result<()> {
+        let exists = path.exists();
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("unable to write timing history {}", path.display()))?;
+        let mut writer = csv::WriterBuilder::new().has_headers(!exists).from_writer(file);
+        for timing in timings.iter() {
+            writer.serialize(timing)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns the timings of one run
+    pub fn run(&self, run: u64) -> Vec<&Timing> {
+        self.timings.iter().filter(|t| t.run == run).collect()
+    }
+
+    /// Returns the most recent run
+    pub fn latest(&self) -> Option<u64> {
+        self.timings.last().map(|t| t.run)
+    }
+
+    /// Returns the run to compare `latest` against: the most recent earlier run of `commit`, if given,
+    /// otherwise the most recent earlier run of a different commit, otherwise the run before `latest`
+    pub fn baseline(&self, latest: u64, commit: Option<&str>) -> Option<u64> {
+        let earlier = self.timings.iter().rev().filter(|t| t.run < latest);
+        if let Some(commit) = commit {
+            return earlier
+                .filter(|t| t.commit.as_deref().is_some_and(|c| c.starts_with(commit)))
+                .map(|t| t.run)
+                .next();
+        }
+        let latest_commit = self.run(latest).first().and_then(|t| t.commit.clone());
+        let mut earlier = earlier.peekable();
+        let previous = earlier.peek().map(|t| t.run);
+        earlier
+            .find(|t| t.commit != latest_commit)
+            .map(|t| t.run)
+            .or(previous)
+    }
+}
+
+/// How one stage of a day compares between two runs
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub before: Duration,
+    pub after: Duration,
+    /// Percentage change, positive when slower
+    pub change: f64,
+    /// Slower by more than the threshold
+    pub regressed: bool,
+}
+
+/// Compares every stage timed in both runs, flagging those more than `threshold` percent slower
+pub fn compare(baseline: &[&Timing], latest: &[&Timing], threshold: f64) -> Vec<Comparison> {
+    latest
+        .iter()
+        .filter_map(|after| {
+            let before = baseline.iter().find(|b| b.day == after.day && b.stage == after.stage)?;
+            let change = match before.median_ns {
+                0 => 0.0,
+                ns => (after.median_ns as f64 - ns as f64) / ns as f64 * 100.0,
+            };
+            Some(Comparison {
+                day: after.day,
+                stage: after.stage,
+                before: Duration::from_nanos(before.median_ns),
+                after: Duration::from_nanos(after.median_ns),
+                change,
+                regressed: change > threshold,
+            })
+        })
+        .collect()
+}
+
+/// Writes a table of comparisons, one row per day and stage
+pub fn write_comparisons(mut writer: impl Write, comparisons: &[Comparison]) -> Result<()> {
+    writeln!(writer, "{:>3}  {:<8}{:>12}{:>12}{:>10}", "Day", "Stage", "Before", "After", "Change")?;
+    for c in comparisons.iter() {
+        let flag = if c.regressed { "  slower" } else { "" };
+        writeln!(writer, "{:>3}  {:<8}{:>12}{:>12}{:>9.1}%{}",
+            c.day, c.stage.to_string(), format!("{:.2?}", c.before), format!("{:.2?}", c.after), c.change, flag)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::find_puzzle;
+
+    fn timing(run: u64, commit: &str, day: u8, stage: Stage, median_ns: u64) -> Timing {
+        Timing { run, commit: Some(commit.to_string()), day, stage, median_ns, samples: 5 }
+    }
+
+    #[test]
+    fn test_measure() {
+        let timings = measure(find_puzzle(2023, 2).unwrap(), "day02-example.txt", 3).unwrap();
+        assert_eq!(timings.iter().map(|(stage, _)| *stage).collect::<Vec<_>>(), Stage::ALL);
+        assert!(measure(find_puzzle(2023, 2).unwrap(), "day02-missing.txt", 3).is_err());
+    }
+
+    #[test]
+    fn test_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PERF_FILE);
+        History::append(&path, &[timing(100, "abc1234", 10, Stage::Part2, 2000)]).unwrap();
+        History::append(&path, &[timing(200, "abc1234", 10, Stage::Part2, 1900)]).unwrap();
+        History::append(&path, &[timing(300, "def5678", 10, Stage::Part2, 1000)]).unwrap();
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.latest(), Some(300));
+        assert_eq!(history.run(200), vec![&timing(200, "abc1234", 10, Stage::Part2, 1900)]);
+        assert_eq!(history.baseline(300, None), Some(200));
+        assert_eq!(history.baseline(300, Some("abc")), Some(200));
+        assert_eq!(history.baseline(200, None), Some(100));
+        assert_eq!(history.baseline(100, None), None);
+        assert_eq!(history.baseline(300, Some("fff")), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = [timing(100, "abc1234", 10, Stage::Part1, 1000), timing(100, "abc1234", 10, Stage::Part2, 1000)];
+        let latest = [
+            timing(200, "def5678", 10, Stage::Part1, 1050),
+            timing(200, "def5678", 10, Stage::Part2, 1200),
+            timing(200, "def5678", 11, Stage::Part1, 1200),
+        ];
+        let comparisons = compare(&baseline.iter().collect::<Vec<_>>(), &latest.iter().collect::<Vec<_>>(), 10.0);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert!((comparisons[1].change - 20.0).abs() < 1e-9);
+
+        let mut output = vec![];
+        write_comparisons(&mut output, &comparisons).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().nth(2), Some(" 10  part 2        1.00µs      1.20µs     20.0%  slower"));
+    }
+}
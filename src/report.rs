@@ -0,0 +1,264 @@
+use std::fmt::Write as _;
+use std::io::Write;
+use std::time::Duration;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use crate::answer::Answer;
+use crate::answers::{Answers, Verification};
+use crate::runner::Run;
+use crate::solution::Part;
+
+/// How a single part fared
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Error,
+    NotImplemented,
+}
+
+/// One machine readable result: a single part of a day against one input
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub title: String,
+    pub input: String,
+    pub answer: Answer,
+    /// Time to prepare the input, in microseconds
+    pub parse_us: Option<u64>,
+    /// Time to solve the part, in microseconds
+    pub solve_us: Option<u64>,
+    pub status: Status,
+    /// Only written, readers check the answer against their own known answers
+    #[serde(skip_deserializing)]
+    pub verification: Verification,
+    /// The known answer, when it differs from `answer`
+    pub expected: Option<Answer>,
+}
+impl Record {
+    /// Returns a record for each part of a completed run, checked against the known answers
+    pub fn from_run(year: u16, day: u8, title: &str, run: &Run, answers: &Answers) -> Vec<Record> {
+        run.parts
+            .iter()
+            .map(|result| {
+                let verification = answers.verify(day, result.part, &run.file_name, &result.answer);
+                Record {
+                    year,
+                    day,
+                    part: result.part.number(),
+                    title: title.to_string(),
+                    input: run.file_name.clone(),
+                    answer: result.answer.clone(),
+                    parse_us: Some(run.parse_time.as_micros() as u64),
+                    solve_us: Some(result.solve_time.as_micros() as u64),
+                    status: match result.answer {
+                        Answer::NotImplemented => Status::NotImplemented,
+                        _ => Status::Ok,
+                    },
+                    expected: verification.expected().cloned(),
+                    verification,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a record for each part of a run whose input could not be prepared
+    pub fn from_error(year: u16, day: u8, title: &str, file_name: &str, parts: &[Part]) -> Vec<Record> {
+        parts
+            .iter()
+            .map(|part| Record {
+                year,
+                day,
+                part: part.number(),
+                title: title.to_string(),
+                input: file_name.to_string(),
+                answer: Answer::NotImplemented,
+                parse_us: None,
+                solve_us: None,
+                status: Status::Error,
+                verification: Verification::Unknown,
+                expected: None,
+            })
+            .collect()
+    }
+
+    /// Returns a record for each part of a day that has not been implemented
+    pub fn from_missing(year: u16, day: u8, parts: &[Part]) -> Vec<Record> {
+        parts
+            .iter()
+            .map(|part| Record {
+                year,
+                day,
+                part: part.number(),
+                title: String::new(),
+                input: String::new(),
+                answer: Answer::NotImplemented,
+                parse_us: None,
+                solve_us: None,
+                status: Status::NotImplemented,
+                verification: Verification::Unknown,
+                expected: None,
+            })
+            .collect()
+    }
+}
+
+/// Returns a table of parse, part 1, part 2 and total times, one row per day and input,
+/// ending with the totals of every row
+pub fn timing_table(records: &[Record]) -> String {
+    let mut rows:Vec<(u8, &str, [Option<u64>; 3])> = vec![];
+    for record in records.iter().filter(|r| r.parse_us.is_some()) {
+        if rows.last().map(|(day, input, _)| (*day, *input)) != Some((record.day, &record.input)) {
+            rows.push((record.day, &record.input, [record.parse_us, None, None]));
+        }
+        let times = &mut rows.last_mut().unwrap().2;
+        times[record.part as usize] = record.solve_us;
+    }
+    let mut totals = [0; 3];
+    let mut table = format!("{:>3}  {:<24}{:>12}{:>12}{:>12}{:>12}\n", "Day", "Input", "Parse", "Part 1", "Part 2", "Total");
+    for (day, input, times) in rows.iter() {
+        for (total, time) in totals.iter_mut().zip(times.iter()) {
+            *total += time.unwrap_or(0);
+        }
+        let _ = writeln!(table, "{:>3}  {:<24}{}", day, input, timing_columns(times));
+    }
+    let _ = writeln!(table, "{:>3}  {:<24}{}", "", "Total", timing_columns(&totals.map(Some)));
+    table
+}
+
+fn timing_columns(times: &[Option<u64>; 3]) -> String {
+    let total:u64 = times.iter().flatten().sum();
+    times
+        .iter()
+        .chain([&Some(total)])
+        .map(|time| match time {
+            Some(us) => format!("{:>12}", format!("{:.2?}", Duration::from_micros(*us))),
+            None => format!("{:>12}", "-"),
+        })
+        .collect()
+}
+
+/// Writes records as JSON lines or CSV with a header row
+pub enum RecordWriter<W: Write> {
+    Json(W),
+    Csv(Box<csv::Writer<W>>),
+}
+impl<W: Write> RecordWriter<W> {
+    pub fn json(writer: W) -> RecordWriter<W> {
+        RecordWriter::Json(writer)
+    }
+
+    pub fn csv(writer: W) -> RecordWriter<W> {
+        RecordWriter::Csv(Box::new(csv::Writer::from_writer(writer)))
+    }
+
+    pub fn write(&mut self, record: &Record) -> Result<()> {
+        match self {
+            RecordWriter::Json(writer) => {
+                serde_json::to_writer(&mut *writer, record)?;
+                writeln!(writer)?;
+            },
+            RecordWriter::Csv(writer) => writer.serialize(record)?,
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        match self {
+            RecordWriter::Json(writer) => writer.flush()?,
+            RecordWriter::Csv(writer) => writer.flush()?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answers::KnownAnswer;
+    use crate::runner::PartResult;
+
+    fn example_run() -> Run {
+        Run {
+            file_name: "day02-example.txt".to_string(),
+            parse_time: Duration::from_micros(12),
+            parts: vec![
+                PartResult { part: Part::One, answer: Answer::Unsigned(8), solve_time: Duration::from_micros(3), metrics: Default::default() },
+                PartResult { part: Part::Two, answer: Answer::NotImplemented, solve_time: Duration::from_micros(1), metrics: Default::default() },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_from_run() {
+        let records = Record::from_run(2023, 2, "Cube Conundrum", &example_run(), &Answers::default());
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].status, Status::Ok);
+        assert_eq!(records[0].parse_us, Some(12));
+        assert_eq!(records[1].status, Status::NotImplemented);
+    }
+
+    #[test]
+    fn test_from_run_verified() {
+        let mut answers = Answers::default();
+        answers.insert(KnownAnswer { day: 2, part: Part::One, input: "day02-example.txt".to_string(), answer: Answer::Unsigned(8) });
+        answers.insert(KnownAnswer { day: 2, part: Part::Two, input: "day02-example.txt".to_string(), answer: Answer::Unsigned(2286) });
+        let records = Record::from_run(2023, 2, "Cube Conundrum", &example_run(), &answers);
+        assert_eq!(records[0].verification, Verification::Verified);
+        assert_eq!(records[1].verification, Verification::Mismatch { expected: Answer::Unsigned(2286) });
+        assert_eq!(records[1].expected, Some(Answer::Unsigned(2286)));
+    }
+
+    #[test]
+    fn test_timing_table() {
+        let mut records = Record::from_run(2023, 2, "Cube Conundrum", &example_run(), &Answers::default());
+        records.extend(Record::from_error(2023, 5, "If You Give A Seed A Fertilizer", "day05.txt", &Part::ALL));
+        records.extend(Record::from_run(2023, 6, "Wait For It", &Run { file_name: "day06.txt".to_string(), ..example_run() }, &Answers::default())[..1].to_vec());
+        let table = timing_table(&records);
+        let lines:Vec<&str> = table.lines().collect();
+        assert_eq!(lines, vec![
+            "Day  Input                          Parse      Part 1      Part 2       Total",
+            "  2  day02-example.txt            12.00µs      3.00µs      1.00µs     16.00µs",
+            "  6  day06.txt                    12.00µs      3.00µs           -     15.00µs",
+            "     Total                        24.00µs      6.00µs      1.00µs     31.00µs",
+        ]);
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut output:Vec<u8> = vec![];
+        {
+            let mut writer = RecordWriter::json(&mut output);
+            for record in Record::from_run(2023, 2, "Cube Conundrum", &example_run(), &Answers::default()) {
+                writer.write(&record).unwrap();
+            }
+            writer.flush().unwrap();
+        }
+        let lines:Vec<&str> = std::str::from_utf8(&output).unwrap().lines().collect();
+        assert_eq!(lines[0], r#"{"year":2023,"day":2,"part":1,"title":"Cube Conundrum","input":"day02-example.txt","answer":"8","parse_us":12,"solve_us":3,"status":"ok","verification":"unknown","expected":null}"#);
+        assert_eq!(lines[1], r#"{"year":2023,"day":2,"part":2,"title":"Cube Conundrum","input":"day02-example.txt","answer":null,"parse_us":12,"solve_us":1,"status":"not_implemented","verification":"unknown","expected":null}"#);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut output:Vec<u8> = vec![];
+        {
+            let mut writer = RecordWriter::csv(&mut output);
+            for record in Record::from_missing(2023, 13, &[Part::One]) {
+                writer.write(&record).unwrap();
+            }
+            for record in Record::from_error(2023, 5, "If You Give A Seed A Fertilizer", "day05.txt", &[Part::One]) {
+                writer.write(&record).unwrap();
+            }
+            writer.flush().unwrap();
+        }
+        let lines:Vec<&str> = std::str::from_utf8(&output).unwrap().lines().collect();
+        assert_eq!(lines, vec![
+            "year,day,part,title,input,answer,parse_us,solve_us,status,verification,expected",
+            "2023,13,1,,,,,,not_implemented,unknown,",
+            "2023,5,1,If You Give A Seed A Fertilizer,day05.txt,,,,error,unknown,",
+        ]);
+    }
+}
@@ -0,0 +1,348 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, bail, Result};
+use rayon::prelude::*;
+use tracing::{debug, info_span};
+use crate::answer::Answer;
+use crate::metrics::{self, Metrics};
+use crate::solution::{Part, Puzzle};
+use crate::{example_files, find_puzzle, find_year, latest_year};
+
+/// The days of a year to run, e.g. `all`, `5`, `3..8` or `3..=8`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Days {
+    All,
+    List(Vec<u8>),
+}
+impl FromStr for Days {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "all" {
+            return Ok(Days::All);
+        }
+        let days:Vec<u8> = if let Some((a, b)) = s.split_once("..=") {
+            (parse_day(a)?..=parse_day(b)?).collect()
+        } else if let Some((a, b)) = s.split_once("..") {
+            (parse_day(a)?..parse_day(b)?).collect()
+        } else {
+            vec![parse_day(s)?]
+        };
+        if days.is_empty() {
+            bail!("{:?} does not include any days", s);
+        }
+        Ok(Days::List(days))
+    }
+}
+
+/// The days to run, as selected on the command line: days of the latest year,
+/// or of the year before a `/`, e.g. `5`, `2023/3..=8`, `2023/all` or just `2023`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Selection {
+    pub year: u16,
+    pub days: Days,
+}
+impl FromStr for Selection {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.split_once('/') {
+            Some((year, days)) => Ok(Selection { year: parse_year(year)?, days: days.parse()? }),
+            // a bare number is a year only if it could be one, so `3..8` is still a range of days
+            None if s.bytes().all(|b| b.is_ascii_digit()) && parse_year(s).is_ok() => {
+                Ok(Selection { year: parse_year(s)?, days: Days::All })
+            },
+            None => Ok(Selection { year: latest_year(), days: s.parse()? }),
+        }
+    }
+}
+impl Selection {
+    /// Returns the selected days that are implemented, and the numbers of those that are not
+    pub fn resolve(&self) -> (Vec<&'static dyn Puzzle>, Vec<u8>) {
+        match &self.days {
+            Days::All => (find_year(self.year).map(|year| year.days.to_vec()).unwrap_or_default(), vec![]),
+            Days::List(days) => {
+                let mut found = vec![];
+                let mut missing = vec![];
+                for day in days.iter() {
+                    match find_puzzle(self.year, *day) {
+                        Some(puzzle) => found.push(puzzle),
+                        None => missing.push(*day),
+                    }
+                }
+                (found, missing)
+            }
+        }
+    }
+}
+
+/// A single day, of the latest year unless one is given, e.g. `5` or `2023/5`
+/// The day need not be implemented, so it can name a day to generate or download
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PuzzleId {
+    pub year: u16,
+    pub day: u8,
+}
+impl FromStr for PuzzleId {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().split_once('/') {
+            Some((year, day)) => Ok(PuzzleId { year: parse_year(year)?, day: parse_day(day)? }),
+            None => Ok(PuzzleId { year: latest_year(), day: parse_day(s)? }),
+        }
+    }
+}
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/// Advent of Code started in 2015
+fn parse_year(s: &str) -> Result<u16> {
+    let year:u16 = s.trim().parse().map_err(|_| anyhow!("{:?} is not a year", s))?;
+    if year < 2015 {
+        bail!("Advent of Code started in 2015, not {}", year);
+    }
+    Ok(year)
+}
+
+fn parse_day(s: &str) -> Result<u8> {
+    let day:u8 = s.trim().parse().map_err(|_| anyhow!("{:?} is not a day", s))?;
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, not {}", day);
+    }
+    Ok(day)
+}
+
+/// Where to find the input for each day
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum InputSource {
+    /// The puzzle input, `dayNN.txt`
+    #[default]
+    Puzzle,
+    /// Every `dayNN-example*.txt` file
+    Examples,
+    /// A single named file in the year's directory
+    File(String),
+    /// Standard input, named `-`
+    Stdin,
+}
+impl InputSource {
+    /// Returns the input file names to run for a day, in its year's directory
+    pub fn files(&self, year: u16, day: u8) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![format!("day{:02}.txt", day)],
+            InputSource::Examples => example_files(year, day),
+            InputSource::File(file_name) => vec![file_name.clone()],
+            InputSource::Stdin => vec!["-".to_string()],
+        }
+    }
+}
+
+/// The answer to one part of a day, and how long it took to solve
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve_time: Duration,
+    /// The work counted while solving, see `metrics::count`
+    pub metrics: Metrics,
+}
+
+/// The results of running a day against one input file
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Run {
+    pub file_name: String,
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Prepares a day's input from a file and solves the requested parts
+pub fn run(puzzle: &dyn Puzzle, file_name: &str, parts: &[Part]) -> Result<Run> {
+    let _day = info_span!("day", year = puzzle.year(), day = puzzle.day(), input = file_name).entered();
+    let start = Instant::now();
+    let input = info_span!("parse").in_scope(|| puzzle.load(file_name))?;
+    Ok(solve(puzzle, file_name, start.elapsed(), input, parts))
+}
+
+/// Prepares a day's input from text and solves the requested parts
+/// `name` stands in for the file name in the results
+pub fn run_input(puzzle: &dyn Puzzle, name: &str, input: &str, parts: &[Part]) -> Result<Run> {
+    let _day = info_span!("day", year = puzzle.year(), day = puzzle.day(), input = name).entered();
+    let start = Instant::now();
+    let input = info_span!("parse").in_scope(|| puzzle.parse(input))?;
+    Ok(solve(puzzle, name, start.elapsed(), input, parts))
+}
+
+fn solve(puzzle: &dyn Puzzle, file_name: &str, parse_time: Duration, input: Box<dyn Any + Send>, parts: &[Part]) -> Run {
+    debug!(?parse_time, "parsed");
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let _solve = info_span!("solve", part = part.number()).entered();
+            metrics::take();
+            let start = Instant::now();
+            let answer = puzzle.solve(*part, input.as_ref());
+            let solve_time = start.elapsed();
+            let metrics = metrics::take();
+            debug!(%answer, ?solve_time, metrics = metrics::summary(&metrics), "solved");
+            PartResult {
+                part: *part,
+                answer,
+                solve_time,
+                metrics,
+            }
+        })
+        .collect();
+    Run {
+        file_name: file_name.to_string(),
+        parse_time,
+        parts,
+    }
+}
+
+/// A day to run against one input file
+#[derive(Clone)]
+pub struct Job {
+    pub puzzle: &'static dyn Puzzle,
+    pub file_name: String,
+    /// The input itself, e.g. read from stdin, instead of reading the file
+    pub input: Option<Arc<str>>,
+}
+
+/// Runs every job on a pool of `threads` threads, or one per CPU if `threads` is 0
+/// Results are handed to `each` as they finish, but always in the order of `jobs`
+pub fn run_all(jobs: &[Job], parts: &[Part], threads: usize, mut each: impl FnMut(&Job, Result<Run>)) -> Result<()> {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build()?;
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        scope.spawn(|| pool.install(|| {
+            jobs.par_iter().enumerate().for_each_with(sender, |sender, (i, job)| {
+                // the receiver only goes away if `each` panicked
+                let result = match &job.input {
+                    Some(input) => run_input(job.puzzle, &job.file_name, input, parts),
+                    None => run(job.puzzle, &job.file_name, parts),
+                };
+                let _ = sender.send((i, result));
+            })
+        }));
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+        for (i, result) in receiver {
+            finished.insert(i, result);
+            while let Some(result) = finished.remove(&next) {
+                each(&jobs[next], result);
+                next += 1;
+            }
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::InputError;
+    use crate::{find_puzzle, read_year_input};
+    use crate::y2023::DAYS;
+
+    #[test]
+    fn test_days_from_str() {
+        assert_eq!("all".parse::<Days>().unwrap(), Days::All);
+        assert_eq!("5".parse::<Days>().unwrap(), Days::List(vec![5]));
+        assert_eq!("3..=5".parse::<Days>().unwrap(), Days::List(vec![3, 4, 5]));
+        assert_eq!("3..5".parse::<Days>().unwrap(), Days::List(vec![3, 4]));
+        assert!("0".parse::<Days>().is_err());
+        assert!("26".parse::<Days>().is_err());
+        assert!("5..5".parse::<Days>().is_err());
+        assert!("five".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn test_selection_from_str() {
+        assert_eq!("5".parse::<Selection>().unwrap(), Selection { year: latest_year(), days: Days::List(vec![5]) });
+        assert_eq!("2023/3..=4".parse::<Selection>().unwrap(), Selection { year: 2023, days: Days::List(vec![3, 4]) });
+        assert_eq!("2022/all".parse::<Selection>().unwrap(), Selection { year: 2022, days: Days::All });
+        assert_eq!("2023".parse::<Selection>().unwrap(), Selection { year: 2023, days: Days::All });
+        assert_eq!("3..8".parse::<Selection>().unwrap(), Selection { year: latest_year(), days: Days::List(vec![3, 4, 5, 6, 7]) });
+        assert_eq!("1..9".parse::<Selection>().unwrap(), Selection { year: latest_year(), days: Days::List((1..9).collect()) });
+        assert!("2014".parse::<Selection>().is_err());
+        assert!("2014/1".parse::<Selection>().is_err());
+        assert!("2023/26".parse::<Selection>().is_err());
+        assert!("twenty/1".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn test_puzzle_id_from_str() {
+        assert_eq!("2024/1".parse::<PuzzleId>().unwrap(), PuzzleId { year: 2024, day: 1 });
+        assert_eq!("5".parse::<PuzzleId>().unwrap(), PuzzleId { year: latest_year(), day: 5 });
+        assert_eq!(PuzzleId { year: 2023, day: 5 }.to_string(), "2023/5");
+        assert!("2023/3..5".parse::<PuzzleId>().is_err());
+    }
+
+    #[test]
+    fn test_days_resolve() {
+        let (found, missing) = Selection { year: 2023, days: Days::List(vec![12, 13, 14]) }.resolve();
+        assert_eq!(found.iter().map(|d| d.day()).collect::<Vec<u8>>(), vec![12, 14]);
+        assert_eq!(missing, vec![13]);
+        let (found, missing) = Selection { year: 2015, days: Days::All }.resolve();
+        assert!(found.is_empty() && missing.is_empty());
+        let (_, missing) = Selection { year: 2015, days: Days::List(vec![1]) }.resolve();
+        assert_eq!(missing, vec![1]);
+    }
+
+    #[test]
+    fn test_run() {
+        let day02 = find_puzzle(2023, 2).unwrap();
+        let results = run(day02, "day02-example.txt", &Part::ALL).unwrap();
+        assert_eq!(results.file_name, "day02-example.txt");
+        assert_eq!(results.parts[0].answer, Answer::Unsigned(8));
+        assert_eq!(results.parts[1].answer, Answer::Unsigned(2286));
+        let error = run(day02, "day02-missing.txt", &Part::ALL).unwrap_err();
+        assert!(matches!(error.downcast_ref::<InputError>(), Some(InputError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_run_metrics() {
+        let results = run(find_puzzle(2023, 8).unwrap(), "day08-example-1.txt", &[Part::One]).unwrap();
+        assert_eq!(results.parts[0].metrics.get("steps"), Some(&2));
+        let results = run(find_puzzle(2023, 2).unwrap(), "day02-example.txt", &[Part::One]).unwrap();
+        assert!(results.parts[0].metrics.is_empty());
+    }
+
+    #[test]
+    fn test_run_input() {
+        let day02 = find_puzzle(2023, 2).unwrap();
+        let results = run_input(day02, "-", "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", &[Part::Two]).unwrap();
+        assert_eq!(results.file_name, "-");
+        assert_eq!(results.parts[0].answer, Answer::Unsigned(48));
+    }
+
+    #[test]
+    fn test_run_all() {
+        // every example that can be read, leaving out the empty one of a day just generated by `new`
+        let jobs:Vec<Job> = DAYS
+            .iter()
+            .flat_map(|puzzle| example_files(puzzle.year(), puzzle.day()).into_iter().map(|file_name| Job { puzzle: *puzzle, file_name, input: None }))
+            .filter(|job| read_year_input(job.puzzle.year(), &job.file_name).is_ok())
+            .chain([Job { puzzle: find_puzzle(2023, 2).unwrap(), file_name: "day02-missing.txt".to_string(), input: None }])
+            .chain([Job { puzzle: find_puzzle(2023, 2).unwrap(), file_name: "-".to_string(), input: Some("Game 1: 2 red".into()) }])
+            .collect();
+        let mut finished = vec![];
+        run_all(&jobs, &Part::ALL, 4, |job, result| {
+            finished.push((job.puzzle.day(), job.file_name.clone()));
+            match result {
+                Ok(result) => assert_eq!(result.file_name, job.file_name),
+                Err(_) => assert_eq!(job.file_name, "day02-missing.txt"),
+            }
+        }).unwrap();
+        let expected:Vec<(u8, String)> = jobs.iter().map(|job| (job.puzzle.day(), job.file_name.clone())).collect();
+        assert_eq!(finished, expected);
+    }
+}
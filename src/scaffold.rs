@@ -0,0 +1,246 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context, Result};
+
+/// The module new days are generated from, written for day 1
+const TEMPLATE: &str = include_str!("template.rs");
+
+/// Generates a new day of a year from `src/template.rs` under the crate `root`:
+/// the module, its registration in the year's module and an empty example input in `year_dir`.
+/// The first day of a new year also generates the year's module and registers it in `src/lib.rs`.
+/// Without a title the day is titled e.g. `Day 13` until it is given one.
+/// Nothing is written if any of the files already exist or the day is already registered.
+/// The bench walks the registry, so the new day is benchmarked without a bench file of its own.
+pub fn new_day(root: &Path, year_dir: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, not {}", day);
+    }
+    let module = format!("day{:02}", day);
+    let year_module = format!("y{}", year);
+    let lib_path = root.join("src").join("lib.rs");
+    let year_path = root.join("src").join(format!("{}.rs", year_module));
+    let module_path = root.join("src").join(&year_module).join(format!("{}.rs", module));
+    let example_path = year_dir.join(format!("{}-example.txt", module));
+
+    for path in [&module_path, &example_path] {
+        if path.exists() {
+            bail!("{} already exists", path.display());
+        }
+    }
+    let (year_source, lib) = if year_path.exists() {
+        let source = fs::read_to_string(&year_path)
+            .with_context(|| format!("unable to read {}", year_path.display()))?;
+        (register(&source, day)?, None)
+    } else {
+        let lib = fs::read_to_string(&lib_path)
+            .with_context(|| format!("unable to read {}", lib_path.display()))?;
+        (render_year(year, day), Some(register_year(&lib, year)?))
+    };
+
+    fs::create_dir_all(root.join("src").join(&year_module))?;
+    let title = match title.trim() {
+        "" => format!("Day {}", day),
+        title => title.to_string(),
+    };
+    fs::write(&module_path, render(day, &title))
+        .with_context(|| format!("unable to write {}", module_path.display()))?;
+    fs::create_dir_all(year_dir)?;
+    fs::write(&example_path, "")
+        .with_context(|| format!("unable to write {}", example_path.display()))?;
+    fs::write(&year_path, year_source)
+        .with_context(|| format!("unable to write {}", year_path.display()))?;
+    let mut written = vec![module_path, example_path, year_path];
+    if let Some(lib) = lib {
+        fs::write(&lib_path, lib)
+            .with_context(|| format!("unable to write {}", lib_path.display()))?;
+        written.push(lib_path);
+    }
+    Ok(written)
+}
+
+/// Returns the template rewritten for a day
+fn render(day: u8, title: &str) -> String {
+    TEMPLATE
+        .replace("Day01", &format!("Day{:02}", day))
+        .replace("day01", &format!("day{:02}", day))
+        .replace("const DAY: u8 = 1;", &format!("const DAY: u8 = {};", day))
+        .replace("const TITLE: &'static str = \"\";", &format!("const TITLE: &'static str = {:?};", title))
+}
+
+/// Returns a new year's module, with its first day declared and in `DAYS`
+fn render_year(year: u16, day: u8) -> String {
+    format!("\
+use crate::solution::Puzzle;
+
+pub mod day{day:02};
+
+pub const YEAR: u16 = {year};
+
+/// Every implemented day, in order
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day{day:02}::Day{day:02},
+];
+")
+}
+
+/// Returns a year's module with the day's module declared and added to `DAYS`, both in day order
+fn register(source: &str, day: u8) -> Result<String> {
+    let module = format!("day{:02}", day);
+    let declaration = format!("pub mod {};", module);
+    let entry = format!("    &{}::Day{:02},", module, day);
+    if source.lines().any(|line| line == declaration) {
+        bail!("{} is already registered", module);
+    }
+    insert_lines(source, [
+        (declaration, "pub mod day", "no day modules to add to"),
+        (entry, "    &day", "no DAYS entries to add to"),
+    ])
+}
+
+/// Returns `lib.rs` with the year's module declared and added to `YEARS`, both in year order
+fn register_year(lib: &str, year: u16) -> Result<String> {
+    let declaration = format!("pub mod y{};", year);
+    let entry = format!("    Year {{ year: {0}, days: y{0}::DAYS }},", year);
+    if lib.lines().any(|line| line == declaration) {
+        bail!("y{} is already registered in src/lib.rs", year);
+    }
+    insert_lines(lib, [
+        (declaration, "pub mod y", "src/lib.rs has no year modules to add to"),
+        (entry, "    Year {", "src/lib.rs has no YEARS entries to add to"),
+    ])
+}
+
+/// Inserts each line into its run of lines starting with a prefix, failing with its message
+/// if there is no such run
+fn insert_lines(source: &str, inserts: [(String, &str, &str); 2]) -> Result<String> {
+    let mut lines:Vec<String> = source.lines().map(String::from).collect();
+    for (line, prefix, missing) in inserts.iter() {
+        insert_sorted(&mut lines, line, |l| l.starts_with(prefix)).context(missing.to_string())?;
+    }
+    let mut output = lines.join("\n");
+    if source.ends_with('\n') {
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// Inserts a line into the run of lines matching `in_group`, keeping the run sorted
+fn insert_sorted(lines: &mut Vec<String>, line: &str, in_group: impl Fn(&str) -> bool) -> Option<()> {
+    let first = lines.iter().position(|l| in_group(l))?;
+    let count = lines[first..].iter().take_while(|l| in_group(l)).count();
+    let offset = lines[first..first + count]
+        .iter()
+        .take_while(|l| l.as_str() < line)
+        .count();
+    lines.insert(first + offset, line.to_string());
+    Some(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const YEAR: &str = "\
+pub mod day01;
+pub mod day14;
+
+/// Every implemented day, in order
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day14::Day14,
+];
+";
+
+    const LIB: &str = "\
+pub mod util;
+pub mod y2023;
+
+/// Every year with solutions, in order
+pub static YEARS: &[Year] = &[
+    Year { year: 2023, days: y2023::DAYS },
+];
+";
+
+    #[test]
+    fn test_register() {
+        let source = register(YEAR, 13).unwrap();
+        assert_eq!(source, "\
+pub mod day01;
+pub mod day13;
+pub mod day14;
+
+/// Every implemented day, in order
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day13::Day13,
+    &day14::Day14,
+];
+");
+        assert!(register(&source, 13).is_err());
+        assert!(register(YEAR, 16).unwrap().contains("    &day14::Day14,\n    &day16::Day16,\n"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib = register_year(LIB, 2024).unwrap();
+        assert!(lib.contains("pub mod y2023;\npub mod y2024;\n"));
+        assert!(lib.contains("    Year { year: 2023, days: y2023::DAYS },\n    Year { year: 2024, days: y2024::DAYS },\n"));
+        assert!(register_year(&lib, 2024).is_err());
+        assert!(render_year(2024, 1).contains("pub mod day01;\n\npub const YEAR: u16 = 2024;"));
+    }
+
+    #[test]
+    fn test_render() {
+        let module = render(13, "Point of Incidence");
+        assert!(module.contains("pub struct Day13;"));
+        assert!(module.contains("impl Solution for Day13 {"));
+        assert!(module.contains("const DAY: u8 = 13;"));
+        assert!(module.contains("const TITLE: &'static str = \"Point of Incidence\";"));
+        assert!(module.contains("prepare(\"day13-example.txt\")"));
+        assert!(!module.contains("day01"));
+    }
+
+    #[test]
+    fn test_template() {
+        use crate::solution::{Part, Puzzle};
+        let day = &crate::y2023::template::Day01;
+        assert_eq!((day.year(), day.day()), (2023, 1));
+        let input = day.parse("#.#\n").unwrap();
+        assert!(!day.solve(Part::One, input.as_ref()).is_implemented());
+        assert!(!day.solve(Part::Two, input.as_ref()).is_implemented());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = tempfile::tempdir().unwrap();
+        let src = root.path().join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), LIB).unwrap();
+        fs::write(src.join("y2023.rs"), YEAR).unwrap();
+        // inputs live outside the crate, as with --input-dir
+        let inputs = tempfile::tempdir().unwrap();
+        let input = inputs.path();
+
+        let created = new_day(root.path(), &input.join("2023"), 2023, 13, "Point of Incidence").unwrap();
+        assert_eq!(created.len(), 3);
+        assert!(src.join("y2023").join("day13.rs").exists());
+        assert!(input.join("2023").join("day13-example.txt").exists());
+        assert!(!root.path().join("input").exists());
+        assert!(fs::read_to_string(src.join("y2023.rs")).unwrap().contains("pub mod day13;"));
+        assert_eq!(fs::read_to_string(src.join("lib.rs")).unwrap(), LIB);
+
+        // the first day of a new year starts its module
+        let created = new_day(root.path(), &input.join("2024"), 2024, 1, "").unwrap();
+        assert_eq!(created.len(), 4);
+        assert!(fs::read_to_string(src.join("y2024").join("day01.rs")).unwrap().contains("const TITLE: &'static str = \"Day 1\";"));
+        assert!(fs::read_to_string(src.join("y2024.rs")).unwrap().contains("    &day01::Day01,"));
+        assert!(fs::read_to_string(src.join("lib.rs")).unwrap().contains("pub mod y2024;"));
+
+        // refuses to overwrite anything
+        let error = new_day(root.path(), &input.join("2023"), 2023, 13, "Point of Incidence").unwrap_err();
+        assert!(error.to_string().contains("already exists"));
+        fs::write(src.join("y2023").join("day01.rs"), "").unwrap();
+        assert!(new_day(root.path(), &input.join("2023"), 2023, 1, "").is_err());
+        assert_eq!(fs::read_to_string(src.join("y2023").join("day01.rs")).unwrap(), "");
+    }
+}
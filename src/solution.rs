@@ -0,0 +1,196 @@
+use std::any::Any;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use crate::answer::Answer;
+use crate::read_year_input;
+
+/// One of the two parts of a day's puzzle
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Deserialize, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum Part {
+    One,
+    Two,
+}
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Returns the part as its puzzle number, `1` or `2`
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        number.to_string().parse()
+    }
+}
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        part.number()
+    }
+}
+impl FromStr for Part {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(anyhow!("part must be 1 or 2, not {:?}", other)),
+        }
+    }
+}
+
+/// A day's puzzle: how to prepare its input and how to solve both parts
+pub trait Solution {
+    /// The prepared input, shared by both parts
+    type Input: Send + 'static;
+
+    /// The year of the puzzle, whose input directory its inputs are read from
+    const YEAR: u16;
+    /// The day of December the puzzle was released
+    const DAY: u8;
+    /// The title of the puzzle
+    const TITLE: &'static str;
+    /// Renders the text of an input for the dashboard, for days that have a visualization
+    const VISUALIZE: Option<fn(&str) -> Result<String>> = None;
+
+    /// Prepares the input from the text of a puzzle input
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Prepares the input from a file in the year's input directory
+    fn prepare(file_name: &str) -> Result<Self::Input> {
+        Self::parse(&read_year_input(Self::YEAR, file_name)?)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer;
+    fn part_2(input: &Self::Input) -> Answer;
+}
+
+/// Object safe view of a `Solution`, so every day can share the registry
+pub trait Puzzle: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+
+    /// Returns the day's input prepared from text, type erased
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send>>;
+
+    /// Returns the day's input prepared from a file in the year's input directory, type erased
+    fn load(&self, file_name: &str) -> Result<Box<dyn Any + Send>>;
+
+    /// Returns the answer to one part, given input returned by `load`
+    fn solve(&self, part: Part, input: &(dyn Any + Send)) -> Answer;
+
+    /// Returns the visualization of a file in the year's input directory, for days that have one
+    fn visualize(&self, file_name: &str) -> Option<Result<String>>;
+}
+impl<S: Solution + Sync> Puzzle for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn load(&self, file_name: &str) -> Result<Box<dyn Any + Send>> {
+        Ok(Box::new(S::prepare(file_name)?))
+    }
+
+    fn solve(&self, part: Part, input: &(dyn Any + Send)) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was not loaded by the same day");
+        match part {
+            Part::One => S::part_1(input),
+            Part::Two => S::part_2(input),
+        }
+    }
+
+    fn visualize(&self, file_name: &str) -> Option<Result<String>> {
+        let render = S::VISUALIZE?;
+        Some(read_year_input(S::YEAR, file_name).map_err(Into::into).and_then(|text| render(&text)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answers::Answers;
+    use crate::{example_files, puzzles, YEARS};
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_registry_order() {
+        let years:Vec<u16> = YEARS.iter().map(|y| y.year).collect();
+        assert!(years.windows(2).all(|pair| pair[0] < pair[1]));
+        for year in YEARS.iter() {
+            let days:Vec<u8> = year.days.iter().map(|d| d.day()).collect();
+            let mut sorted = days.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(days, sorted);
+            assert!(year.days.iter().all(|d| d.year() == year.year));
+        }
+        assert!(puzzles().all(|d| !d.title().is_empty()));
+    }
+
+    #[test]
+    fn test_parse() {
+        let day02 = crate::find_puzzle(2023, 2).unwrap();
+        let input = day02.parse("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        assert_eq!(day02.solve(Part::One, input.as_ref()), Answer::Unsigned(1));
+        assert_eq!(day02.solve(Part::Two, input.as_ref()), Answer::Unsigned(48));
+    }
+
+    #[test]
+    fn test_registry_examples() {
+        // every example with a known answer loads and solves to it,
+        // examples of a day just generated by `new` have none yet
+        let mut checked = 0;
+        for day in puzzles() {
+            let answers = Answers::load(day.year()).unwrap();
+            for file_name in example_files(day.year(), day.day()).iter() {
+                let known:Vec<(Part, &Answer)> = Part::ALL
+                    .into_iter()
+                    .filter_map(|part| Some((part, answers.get(day.day(), part, file_name)?)))
+                    .collect();
+                if known.is_empty() {
+                    continue;
+                }
+                let input = day.load(file_name).unwrap();
+                for (part, expected) in known {
+                    assert_eq!(&day.solve(part, input.as_ref()), expected, "{}/{} part {} {}", day.year(), day.day(), part, file_name);
+                    checked += 1;
+                }
+            }
+        }
+        assert!(checked > 0);
+    }
+}
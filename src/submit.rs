@@ -0,0 +1,334 @@
+use std::fmt;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use crate::answer::Answer;
+use crate::client::Client;
+use crate::solution::Part;
+
+/// Name of the submission log in each year's directory
+pub const SUBMISSIONS_FILE: &str = "submissions.csv";
+
+/// How long to wait when the site says an answer came too soon, but not how long to wait
+pub const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+/// How many times `submit_waiting` sends an answer the site keeps saying came too soon
+pub const MAX_ATTEMPTS: usize = 3;
+
+/// What the puzzle site made of a submitted answer
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    TooRecent,
+    AlreadySolved,
+    Unrecognized,
+}
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooRecent => "submitted too recently",
+            Outcome::AlreadySolved => "already solved",
+            Outcome::Unrecognized => "unrecognized response",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// The response page to a submitted answer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// How long the site asks us to wait before the next submission
+    pub wait: Option<Duration>,
+}
+impl Response {
+    pub fn parse(page: &str) -> Response {
+        let outcome = if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if page.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::TooRecent
+        } else if page.contains("Did you already complete it") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unrecognized
+        };
+        Response { outcome, wait: parse_wait(page) }
+    }
+}
+
+/// Reads the wait from "You have 4m 32s left to wait" or "Please wait one minute"
+fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some((before, _)) = page.split_once(" left to wait") {
+        let left = before.rsplit("You have ").next()?;
+        let mut seconds = 0;
+        for amount in left.split_whitespace() {
+            let unit = amount.chars().last()?;
+            let count:u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+            seconds += count * match unit {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+    let (_, after) = page.split_once("lease wait ")?;
+    let mut words = after.split_whitespace();
+    let count:u64 = match words.next()? {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(count * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(count)),
+        _ => None,
+    }
+}
+
+/// One answer that was submitted, and how it went
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub outcome: Outcome,
+    /// When the answer was submitted, in seconds since the epoch
+    pub submitted_at: u64,
+    /// When the site allows the next submission, in seconds since the epoch
+    pub wait_until: Option<u64>,
+}
+
+/// Reasons an answer is not sent to the site
+#[derive(Debug, Error)]
+pub enum Refusal {
+    #[error("day {day} part {part} has already been solved")]
+    Solved { day: u8, part: Part },
+
+    #[error("{answer} was already submitted for day {day} part {part}, it was {outcome}")]
+    Repeated { day: u8, part: Part, answer: Answer, outcome: Outcome },
+
+    #[error("{answer} can't be right for day {day} part {part}, {bound} was already {outcome}")]
+    OutOfBounds { day: u8, part: Part, answer: Answer, bound: Answer, outcome: Outcome },
+
+    #[error("the site asked to wait another {}s before submitting", .0.as_secs())]
+    Throttled(Duration),
+}
+
+/// Every answer submitted so far, kept in `submissions.csv`
+#[derive(Clone, Debug)]
+pub struct Submissions {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+impl Submissions {
+    /// Loads the submission log, a missing file has no submissions
+    pub fn load(path: &Path) -> Result<Submissions> {
+        let mut submissions = vec![];
+        if path.exists() {
+            let mut reader = csv::Reader::from_path(path)
+                .with_context(|| format!("unable to read submissions file {}", path.display()))?;
+            for row in reader.deserialize() {
+                submissions.push(row.with_context(|| format!("invalid submissions file {}", path.display()))?);
+            }
+        }
+        Ok(Submissions { path: path.to_path_buf(), submissions })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter()
+    }
+
+    /// Returns why an answer should not be submitted at `now`, if it shouldn't
+    /// Answers at or past one the site said was too high or too low are refused too
+    pub fn check(&self, day: u8, part: Part, answer: &Answer, now: u64) -> Result<(), Refusal> {
+        let previous = self.submissions.iter().filter(|s| s.day == day && s.part == part);
+        for submission in previous {
+            if submission.outcome == Outcome::Correct || submission.outcome == Outcome::AlreadySolved {
+                return Err(Refusal::Solved { day, part });
+            }
+            if submission.answer == *answer && submission.outcome != Outcome::TooRecent {
+                return Err(Refusal::Repeated { day, part, answer: answer.clone(), outcome: submission.outcome });
+            }
+            let beyond = match submission.outcome {
+                Outcome::TooHigh => *answer >= submission.answer,
+                Outcome::TooLow => *answer <= submission.answer,
+                _ => false,
+            };
+            if beyond {
+                return Err(Refusal::OutOfBounds {
+                    day,
+                    part,
+                    answer: answer.clone(),
+                    bound: submission.answer.clone(),
+                    outcome: submission.outcome,
+                });
+            }
+        }
+        match self.submissions.iter().filter_map(|s| s.wait_until).max() {
+            Some(wait_until) if wait_until > now => Err(Refusal::Throttled(Duration::from_secs(wait_until - now))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Submits an answer, unless `check` refuses it, and records the outcome
+    pub fn submit(&mut self, client: &Client, day: u8, part: Part, answer: &Answer, now: u64) -> Result<Submission> {
+        self.check(day, part, answer, now)?;
+        let response = client.submit(day, part, answer)?;
+        // always wait before trying again after coming too soon, even if the page didn't say how long
+        let wait = match response.outcome {
+            Outcome::TooRecent => response.wait.or(Some(DEFAULT_WAIT)),
+            _ => response.wait,
+        };
+        let submission = Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            outcome: response.outcome,
+            submitted_at: now,
+            wait_until: wait.map(|wait| now + wait.as_secs()),
+        };
+        self.submissions.push(submission.clone());
+        self.save()?;
+        Ok(submission)
+    }
+
+    /// Submits an answer, sleeping through any wait the site asks for and trying again
+    /// Gives up with the last submission once the site has said it came too soon `MAX_ATTEMPTS` times
+    pub fn submit_waiting(
+        &mut self,
+        client: &Client,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        mut now: impl FnMut() -> u64,
+        mut sleep: impl FnMut(Duration),
+    ) -> Result<Submission> {
+        let mut attempts = 0;
+        loop {
+            match self.submit(client, day, part, answer, now()) {
+                Ok(submission) if submission.outcome == Outcome::TooRecent => {
+                    attempts += 1;
+                    if attempts >= MAX_ATTEMPTS {
+                        return Ok(submission);
+                    }
+                },
+                Ok(submission) => return Ok(submission),
+                Err(error) => match error.downcast_ref::<Refusal>() {
+                    Some(Refusal::Throttled(wait)) => sleep(*wait),
+                    _ => return Err(error),
+                },
+            }
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        let mut writer = csv::Writer::from_path(&self.path)
+            .with_context(|| format!("unable to write submissions file {}", self.path.display()))?;
+        for submission in self.submissions.iter() {
+            writer.serialize(submission)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Returns the current time in seconds since the epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::test::{serve, serve_many};
+    use std::cell::Cell;
+
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations.</p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2023/day/5\">Return to Day 5</a>]</p></article></main>";
+    const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. [<a href=\"/2023/day/5\">Return to Day 5</a>]</p></article></main>";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Response::parse(CORRECT), Response { outcome: Outcome::Correct, wait: None });
+        assert_eq!(Response::parse(TOO_HIGH), Response { outcome: Outcome::TooHigh, wait: Some(Duration::from_secs(60)) });
+        assert_eq!(Response::parse(TOO_RECENT), Response { outcome: Outcome::TooRecent, wait: Some(Duration::from_secs(272)) });
+        assert_eq!(Response::parse("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.").wait, Some(Duration::from_secs(300)));
+        assert_eq!(Response::parse("That's not the right answer.").outcome, Outcome::Incorrect);
+        assert_eq!(Response::parse("You don't seem to be solving the right level.  Did you already complete it?").outcome, Outcome::AlreadySolved);
+        assert_eq!(Response::parse("<html></html>"), Response { outcome: Outcome::Unrecognized, wait: None });
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut submissions = Submissions::load(&dir.path().join(SUBMISSIONS_FILE)).unwrap();
+        submissions.submissions.push(Submission {
+            day: 5, part: Part::One, answer: Answer::Unsigned(500), outcome: Outcome::TooHigh,
+            submitted_at: 1000, wait_until: Some(1060),
+        });
+        assert!(matches!(submissions.check(5, Part::One, &Answer::Unsigned(400), 1030), Err(Refusal::Throttled(wait)) if wait.as_secs() == 30));
+        assert!(matches!(submissions.check(5, Part::One, &Answer::Unsigned(500), 2000), Err(Refusal::Repeated { outcome: Outcome::TooHigh, .. })));
+        assert!(matches!(submissions.check(5, Part::One, &Answer::Signed(501), 2000), Err(Refusal::OutOfBounds { .. })));
+        assert!(submissions.check(5, Part::One, &Answer::Unsigned(400), 1060).is_ok());
+        assert!(submissions.check(5, Part::Two, &Answer::Unsigned(500), 1060).is_ok());
+    }
+
+    #[test]
+    fn test_submit_waiting() {
+        let dir = tempfile::tempdir().unwrap();
+        let too_recent = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.</p></article></main>";
+        let (base_url, server) = serve_many(200, too_recent, MAX_ATTEMPTS);
+        let client = Client::new("abc123", &base_url, 2023);
+        let mut submissions = Submissions::load(&dir.path().join(SUBMISSIONS_FILE)).unwrap();
+        let now = Cell::new(1000);
+        let mut sleeps = vec![];
+        let submission = submissions.submit_waiting(&client, 5, Part::One, &Answer::Unsigned(500), || now.get(), |wait| {
+            sleeps.push(wait.as_secs());
+            now.set(now.get() + wait.as_secs());
+        }).unwrap();
+        // each retry waits the default time, and the site is only asked MAX_ATTEMPTS times
+        assert_eq!(server.join().unwrap().len(), MAX_ATTEMPTS);
+        assert_eq!(sleeps, vec![DEFAULT_WAIT.as_secs(); MAX_ATTEMPTS - 1]);
+        assert_eq!(submission.outcome, Outcome::TooRecent);
+        assert_eq!(submission.wait_until, Some(now.get() + DEFAULT_WAIT.as_secs()));
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SUBMISSIONS_FILE);
+        let (base_url, server) = serve(200, TOO_HIGH);
+        let client = Client::new("abc123", &base_url, 2023);
+        let mut submissions = Submissions::load(&path).unwrap();
+        let submission = submissions.submit(&client, 5, Part::Two, &Answer::Unsigned(500), 1000).unwrap();
+        assert_eq!(submission.outcome, Outcome::TooHigh);
+        assert_eq!(submission.wait_until, Some(1060));
+        server.join().unwrap();
+
+        // the outcome is recorded, and the wait respected without contacting the site
+        let submissions = Submissions::load(&path).unwrap();
+        assert_eq!(submissions.iter().collect::<Vec<_>>(), vec![&submission]);
+        assert!(matches!(submissions.check(5, Part::Two, &Answer::Unsigned(400), 1001), Err(Refusal::Throttled(_))));
+    }
+}
@@ -1,19 +1,44 @@
-use crate::read_input_lines;
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
 use anyhow::Result;
 
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(String::from).collect())
+}
+
 pub fn prepare(file_name: &str) -> Result<Vec<String>> {
-    let input = read_input_lines(file_name);
-    Ok(input)
+    parse(&read_year_input(super::YEAR, file_name)?)
 }
 
-pub fn part_1(_input: &Vec<String>) -> Option<usize> {
+pub fn part_1(_input: &[String]) -> Option<usize> {
     None
 }
 
-pub fn part_2(_input: &Vec<String>) -> Option<usize> {
+pub fn part_2(_input: &[String]) -> Option<usize> {
     None
 }
 
+pub struct Day01;
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -22,16 +47,14 @@ mod test {
     #[test]
     #[ignore]
     fn test_part_1() {
-        if let Ok(input) = prepare("day01-example.txt") {
-            assert_eq!(part_1(&input), Some(1))
-        }
+        let input = prepare("day01-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(1))
     }
 
     #[test]
     #[ignore]
     fn test_part_2() {
-        if let Ok(input) = prepare("day01-example.txt") {
-            assert_eq!(part_2(&input), Some(1))
-        }
+        let input = prepare("day01-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(1))
     }
-}
\ No newline at end of file
+}
@@ -1,16 +1,98 @@
 use std::cmp::{min, max};
-use std::ops::Range;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Range, RangeFrom, RangeInclusive};
+use std::slice;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The integer types a `Span` can cover
+pub trait Integer: Copy + Ord + Debug + Display + FromStr {
+    /// The unsigned type of the same width, which any distance between two values fits in
+    type Unsigned: Integer;
+
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Returns the distance between two values, which can't overflow
+    fn distance(self, other: Self) -> Self::Unsigned;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_add_unsigned(self, other: Self::Unsigned) -> Option<Self>;
+    fn checked_sub_unsigned(self, other: Self::Unsigned) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($add_unsigned:ident, $sub_unsigned:ident; $($t:ty => $unsigned:ty),*) => {
+        $(impl Integer for $t {
+            type Unsigned = $unsigned;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn distance(self, other: Self) -> $unsigned {
+                self.abs_diff(other)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_add_unsigned(self, other: $unsigned) -> Option<Self> {
+                <$t>::$add_unsigned(self, other)
+            }
+
+            fn checked_sub_unsigned(self, other: $unsigned) -> Option<Self> {
+                <$t>::$sub_unsigned(self, other)
+            }
+        })*
+    };
+}
+integer!(checked_add, checked_sub; u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize);
+integer!(checked_add_unsigned, checked_sub_unsigned; i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+/// Reasons a `Span` could not be built, parsed or converted
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum SpanError<T: Integer> {
+    #[error("span ends at {end} before it starts at {start}")]
+    Reversed { start: T, end: T },
+
+    #[error("span from {start} ends past the largest value of its type")]
+    Overflow { start: T },
+
+    #[error("span at {start} is empty, so it has no last value")]
+    Empty { start: T },
+
+    #[error("span from {start} ends at {end}, so it isn't unbounded")]
+    Bounded { start: T, end: T },
+
+    #[error("{0:?} is not a span, expected a..b, a..=b, a-b, a.. or start length")]
+    Format(String),
+
+    #[error("{0:?} is not a valid span bound")]
+    Number(String),
+}
 
 /// A range from `start` to `end` that supports set operations with other `Span`s
+/// The range is half-open, it includes `start` but not `end`, so no `Span` covers the
+/// largest value of its type, and an unbounded `Span` is one that ends at that value
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Span {
-    pub start: usize,
-    pub end:   usize,
+pub struct Span<T = usize> {
+    pub start: T,
+    pub end:   T,
 }
-impl Span {
+impl<T: Integer> Span<T> {
 
     /// Returns a `Span` based on two values in ascending order
-    pub fn new(a:usize, b:usize) -> Span {
+    /// Use `half_open` where reversed values are a mistake
+    pub fn new(a:T, b:T) -> Span<T> {
         if a < b {
             Span {start: a, end: b}
         } else {
@@ -18,33 +100,86 @@ impl Span {
         }
     }
 
-    /// Returns the length of the `Span`
-    pub fn len(&self) -> usize {
-        self.end - self.start
+    /// Returns the `Span` from `start` up to but not including `end`
+    pub fn half_open(start: T, end: T) -> Result<Span<T>, SpanError<T>> {
+        if end < start {
+            return Err(SpanError::Reversed { start, end });
+        }
+        Ok(Span { start, end })
+    }
+
+    /// Returns the `Span` from `first` up to and including `last`
+    /// A `last` of the largest value of the type can't be covered, so it is an `Overflow`
+    pub fn inclusive(first: T, last: T) -> Result<Span<T>, SpanError<T>> {
+        if last < first {
+            return Err(SpanError::Reversed { start: first, end: last });
+        }
+        let end = last.checked_add(T::ONE).ok_or(SpanError::Overflow { start: first })?;
+        Ok(Span { start: first, end })
+    }
+
+    /// Returns the `Span` of `len` values from `start`
+    pub fn from_len(start: T, len: T::Unsigned) -> Result<Span<T>, SpanError<T>> {
+        let end = start.checked_add_unsigned(len).ok_or(SpanError::Overflow { start })?;
+        Ok(Span { start, end })
+    }
+
+    /// Returns the `Span` from `start` to the largest value of its type
+    /// The end is still excluded, so the largest value itself is not covered,
+    /// and this is the same `Span` as `half_open(start, T::MAX)`
+    pub fn unbounded(start: T) -> Span<T> {
+        Span { start, end: T::MAX }
+    }
+
+    /// Checks if the `Span` runs to the largest value of its type
+    /// That includes any span built with an end of `T::MAX`, not only those from `unbounded`
+    pub fn is_unbounded(&self) -> bool {
+        self.end == T::MAX
+    }
+
+    /// Returns the last value in the `Span`, if it isn't empty
+    pub fn last(&self) -> Option<T> {
+        if self.is_empty() { None } else { self.end.checked_sub(T::ONE) }
+    }
+
+    /// Returns the length of the `Span`, as the unsigned type of the same width
+    /// so that spans of signed values, e.g. `i8::MIN..i8::MAX`, can't overflow
+    pub fn len(&self) -> T::Unsigned {
+        self.end.distance(self.start)
+    }
+
+    /// Returns the length of the `Span` as its own type, if it fits
+    pub fn checked_len(&self) -> Option<T> {
+        self.end.checked_sub(self.start)
+    }
+
+    /// Checks if the `Span` covers no values
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
     }
 
     /// Returns the `Span` as a `Range`
-    pub fn to_range(&self) -> Range<usize> {
+    pub fn to_range(&self) -> Range<T> {
         self.start..self.end
     }
 
     /// Checks if another `Span` intersects with this `Span`
-    pub fn intersects(&self, other: &Span) -> bool {
+    pub fn intersects(&self, other: &Span<T>) -> bool {
         !(self.end <= other.start || self.start >= other.end)
     }
 
     /// Checks if another `Span` is fully within this `Span`'s bounds
-    pub fn contains(&self, other: &Span) -> bool {
+    pub fn contains(&self, other: &Span<T>) -> bool {
         self.start <= other.start && other.end <= self.end
     }
 
-    /// Checks if a `usize` value is within this `Span`'s bounds
-    pub fn contains_value(&self, value: usize) -> bool {
+    /// Checks if a value is within this `Span`'s bounds
+    pub fn contains_value(&self, value: T) -> bool {
         self.start <= value && value < self.end
     }
 
     /// Returns a new`Span` covering the intersecting values
-    pub fn intersection(&self, other: &Span) -> Option<Span> {
+    pub fn intersection(&self, other: &Span<T>) -> Option<Span<T>> {
         // a ......|--------|....
         // b ...|-----|..........
         // = ......|==|..........
@@ -57,7 +192,7 @@ impl Span {
     }
 
     /// Returns a new`Span` covering the combined values
-    pub fn union(&self, other:&Span) -> Option<Span> {
+    pub fn union(&self, other:&Span<T>) -> Option<Span<T>> {
         // a ......|--------|....
         // b ...|-----|..........
         // = ...|===========|....
@@ -71,7 +206,7 @@ impl Span {
 
     /// Returns a list of new`Span`s containing the difference
     /// between this and another `Span`
-    pub fn subtraction(&self, other: &Span) -> Option<Vec<Span>> {
+    pub fn subtraction(&self, other: &Span<T>) -> Option<Vec<Span<T>>> {
         // a ...|-----------|....
         // b ......|-----|.......
         // = ...|==|.....|==|....
@@ -81,12 +216,12 @@ impl Span {
             Some(vec![self.clone()])
         } else {
             let intersection = self.intersection(other).unwrap();
-            let mut values:Vec<usize> = vec![self.start, self.end, intersection.start, intersection.end];
+            let mut values:Vec<T> = vec![self.start, self.end, intersection.start, intersection.end];
             values.sort();
             let output = values
                 .chunks(2)
                 .map(|x| Span {start: x[0], end: x[1]})
-                .filter(|i| i.len() > 0)
+                .filter(|i| !i.is_empty())
                 .collect();
             Some(output)
         }
@@ -95,7 +230,7 @@ impl Span {
     /// Returns a list of new `Span`s containing the bounds
     /// of this `Span` split at the boundary intersection
     /// values of another `span`
-    pub fn division(&self, other: &Span) -> Option<Vec<Span>> {
+    pub fn division(&self, other: &Span<T>) -> Option<Vec<Span<T>>> {
         // a ...|-----------|....
         // b ......|-----|.......
         // = ...|==|=====|==|....
@@ -106,15 +241,550 @@ impl Span {
             if let Some(intersection) = self.intersection(other) {
                 output.push(intersection);
             }
-            output.sort_by(|a, b| a.start.cmp(&b.start));
+            output.sort_by_key(|a| a.start);
             Some(output)
         } else { None }
     }
 }
+/// Shows `start..end`, or `start..` for a span ending at the largest value of its type
+impl<T: Integer> Display for Span<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_unbounded() {
+            write!(f, "{}..", self.start)
+        } else {
+            write!(f, "{}..{}", self.start, self.end)
+        }
+    }
+}
+impl<T: Integer> FromStr for Span<T> {
+    type Err = SpanError<T>;
+
+    /// Parses `a..b`, `a..=b`, `a-b`, `a..` or `start length`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((first, last)) = s.split_once("..=") {
+            return Span::inclusive(bound(first)?, bound(last)?);
+        }
+        if let Some((start, end)) = s.split_once("..") {
+            return match end.trim() {
+                "" => Ok(Span::unbounded(bound(start)?)),
+                end => Span::half_open(bound(start)?, bound(end)?),
+            };
+        }
+        if let Some((start, len)) = s.split_once(char::is_whitespace) {
+            return Span::from_len(bound(start)?, bound(len)?);
+        }
+        // skip the sign of a negative first value
+        if let Some((i, _)) = s.char_indices().skip(1).find(|(_, c)| *c == '-') {
+            return Span::inclusive(bound(&s[..i])?, bound(&s[i + 1..])?);
+        }
+        Err(SpanError::Format(s.to_string()))
+    }
+}
+
+fn bound<N: FromStr, T: Integer>(s: &str) -> Result<N, SpanError<T>> {
+    s.trim().parse().map_err(|_| SpanError::Number(s.to_string()))
+}
+
+impl<T: Integer> TryFrom<RangeInclusive<T>> for Span<T> {
+    type Error = SpanError<T>;
+    fn try_from(range: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        Span::inclusive(*range.start(), *range.end())
+    }
+}
+impl<T: Integer> TryFrom<Span<T>> for RangeInclusive<T> {
+    type Error = SpanError<T>;
+    fn try_from(span: Span<T>) -> Result<Self, Self::Error> {
+        let last = span.last().ok_or(SpanError::Empty { start: span.start })?;
+        Ok(span.start..=last)
+    }
+}
+impl<T: Integer> From<RangeFrom<T>> for Span<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        Span::unbounded(range.start)
+    }
+}
+impl<T: Integer> TryFrom<Span<T>> for RangeFrom<T> {
+    type Error = SpanError<T>;
+    fn try_from(span: Span<T>) -> Result<Self, Self::Error> {
+        if !span.is_unbounded() {
+            return Err(SpanError::Bounded { start: span.start, end: span.end });
+        }
+        Ok(span.start..)
+    }
+}
+
+/// A set of values kept as `Span`s that are sorted, disjoint and merged,
+/// so no two spans overlap or touch and none are empty
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SpanSet<T = usize> {
+    spans: Vec<Span<T>>,
+}
+impl<T: Integer> SpanSet<T> {
+
+    pub fn new() -> SpanSet<T> {
+        SpanSet { spans: vec![] }
+    }
+
+    /// Adds the values of a `Span`, merging it with any spans it overlaps or touches
+    pub fn insert(&mut self, span: Span<T>) {
+        if span.is_empty() {
+            return;
+        }
+        // spans entirely before or after the new one stay as they are
+        let before = self.spans.partition_point(|s| s.end < span.start);
+        let after = self.spans.partition_point(|s| s.start <= span.end);
+        let merged = self.spans[before..after]
+            .iter()
+            .fold(span, |a, b| Span { start: min(a.start, b.start), end: max(a.end, b.end) });
+        self.spans.splice(before..after, [merged]);
+    }
+
+    /// Returns the spans of the set, in order
+    pub fn spans(&self) -> &[Span<T>] {
+        &self.spans
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Span<T>> {
+        self.spans.iter()
+    }
+
+    /// Checks if the set has no values
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the number of values in the set
+    /// The spans are disjoint, so the total fits in the unsigned type of the same width
+    pub fn len(&self) -> T::Unsigned {
+        self.spans
+            .iter()
+            .fold(T::Unsigned::ZERO, |total, span| total.checked_add(span.len()).expect("disjoint spans fit in their type"))
+    }
+
+    /// Returns the smallest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.spans.first().map(|span| span.start)
+    }
+
+    /// Returns the largest value in the set
+    pub fn max(&self) -> Option<T> {
+        // spans aren't empty, so the end is above the start
+        self.spans.last().and_then(|span| span.end.checked_sub(T::ONE))
+    }
+
+    /// Checks if a value is in the set
+    pub fn contains_value(&self, value: T) -> bool {
+        let i = self.spans.partition_point(|s| s.end <= value);
+        self.spans.get(i).is_some_and(|span| span.contains_value(value))
+    }
+
+    /// Checks if every value of a `Span` is in the set
+    pub fn contains(&self, span: &Span<T>) -> bool {
+        if span.is_empty() {
+            return true;
+        }
+        let i = self.spans.partition_point(|s| s.end <= span.start);
+        self.spans.get(i).is_some_and(|s| s.contains(span))
+    }
+
+    /// Returns the values in either set
+    pub fn union(&self, other: &SpanSet<T>) -> SpanSet<T> {
+        let mut output = self.clone();
+        for span in other.iter() {
+            output.insert(span.clone());
+        }
+        output
+    }
+
+    /// Returns the values in both sets
+    pub fn intersection(&self, other: &SpanSet<T>) -> SpanSet<T> {
+        // walk both sets together, moving on from whichever span ends first
+        let mut spans = vec![];
+        let (mut a, mut b) = (self.spans.iter().peekable(), other.spans.iter().peekable());
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if let Some(span) = x.intersection(y) {
+                spans.push(span);
+            }
+            if x.end <= y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        SpanSet { spans }
+    }
+
+    /// Returns the values in this set but not the other
+    pub fn difference(&self, other: &SpanSet<T>) -> SpanSet<T> {
+        let mut spans = vec![];
+        for span in self.spans.iter() {
+            let mut remaining = vec![span.clone()];
+            let first = other.spans.partition_point(|s| s.end <= span.start);
+            for removed in other.spans[first..].iter().take_while(|s| s.start < span.end) {
+                remaining = remaining
+                    .iter()
+                    .flat_map(|r| r.subtraction(removed).unwrap_or_default())
+                    .collect();
+            }
+            spans.extend(remaining);
+        }
+        SpanSet { spans }
+    }
+
+    /// Returns the values in exactly one of the sets
+    pub fn symmetric_difference(&self, other: &SpanSet<T>) -> SpanSet<T> {
+        self.difference(other).union(&other.difference(self))
+    }
+}
+impl<T: Integer> From<Span<T>> for SpanSet<T> {
+    fn from(span: Span<T>) -> SpanSet<T> {
+        let mut set = SpanSet::new();
+        set.insert(span);
+        set
+    }
+}
+impl<T: Integer> FromIterator<Span<T>> for SpanSet<T> {
+    fn from_iter<I: IntoIterator<Item = Span<T>>>(spans: I) -> SpanSet<T> {
+        let mut spans:Vec<Span<T>> = spans.into_iter().filter(|s| !s.is_empty()).collect();
+        spans.sort_by_key(|s| s.start);
+        let mut merged:Vec<Span<T>> = vec![];
+        for span in spans {
+            match merged.last_mut() {
+                Some(last) if span.start <= last.end => last.end = max(last.end, span.end),
+                _ => merged.push(span),
+            }
+        }
+        SpanSet { spans: merged }
+    }
+}
+impl<'a, T: Integer> IntoIterator for &'a SpanSet<T> {
+    type Item = &'a Span<T>;
+    type IntoIter = slice::Iter<'a, Span<T>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Moves values up or down by a fixed distance
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Offset<T: Integer> {
+    Up(T::Unsigned),
+    Down(T::Unsigned),
+}
+impl<T: Integer> Offset<T> {
+
+    /// Returns the `Offset` that moves `from` to `to`
+    pub fn new(from: T, to: T) -> Offset<T> {
+        if from <= to {
+            Offset::Up(to.distance(from))
+        } else {
+            Offset::Down(from.distance(to))
+        }
+    }
+
+    /// Returns the moved value, if it fits in its type
+    pub fn apply(&self, value: T) -> Option<T> {
+        match *self {
+            Offset::Up(by) => value.checked_add_unsigned(by),
+            Offset::Down(by) => value.checked_sub_unsigned(by),
+        }
+    }
+
+    /// Returns the moved `Span`, if both its bounds fit in their type
+    pub fn apply_span(&self, span: &Span<T>) -> Option<Span<T>> {
+        Some(Span { start: self.apply(span.start)?, end: self.apply(span.end)? })
+    }
+}
+
+/// A map from disjoint `Span`s to values, sorted by start
+/// Spans with the same value aren't merged, so each keeps the span it was inserted with
+/// or the part of it that wasn't overridden
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpanMap<V, T = usize> {
+    entries: Vec<(Span<T>, V)>,
+}
+impl<V, T: Integer> Default for SpanMap<V, T> {
+    fn default() -> Self {
+        SpanMap::new()
+    }
+}
+impl<V, T: Integer> SpanMap<V, T> {
+
+    pub fn new() -> SpanMap<V, T> {
+        SpanMap { entries: vec![] }
+    }
+
+    /// Maps the values of a `Span` to `value`, overriding any spans it overlaps
+    /// The parts of overridden spans outside the new one keep their values
+    pub fn insert(&mut self, span: Span<T>, value: V) where V: Clone {
+        // a ...|-----|..|--|..|-----|...
+        // b ......|--------------|......
+        // = ...|a|b==============|a|....
+        if span.is_empty() {
+            return;
+        }
+        let first = self.entries.partition_point(|(s, _)| s.end <= span.start);
+        let last = self.entries.partition_point(|(s, _)| s.start < span.end);
+        let mut replacement = vec![];
+        if let Some((s, v)) = self.entries[first..last].first() {
+            if s.start < span.start {
+                replacement.push((Span { start: s.start, end: span.start }, v.clone()));
+            }
+        }
+        let end = span.end;
+        replacement.push((span, value));
+        if let Some((s, v)) = self.entries[first..last].last() {
+            if end < s.end {
+                replacement.push((Span { start: end, end: s.end }, v.clone()));
+            }
+        }
+        self.entries.splice(first..last, replacement);
+    }
+
+    /// Returns the value mapped to a point
+    pub fn get(&self, value: T) -> Option<&V> {
+        let i = self.entries.partition_point(|(s, _)| s.end <= value);
+        self.entries
+            .get(i)
+            .filter(|(s, _)| s.contains_value(value))
+            .map(|(_, v)| v)
+    }
+
+    /// Returns the covered spans and their values, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&Span<T>, &V)> {
+        self.entries.iter().map(|(s, v)| (s, v))
+    }
+
+    /// Checks if the map has no spans
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the values covered by any span
+    pub fn covered(&self) -> SpanSet<T> {
+        self.entries.iter().map(|(s, _)| s.clone()).collect()
+    }
+
+    /// Splits a `Span` at the bounds of the map, in order,
+    /// with the value of each covered segment and `None` for each uncovered one
+    pub fn segments(&self, span: &Span<T>) -> Vec<(Span<T>, Option<&V>)> {
+        // a ......|-----|..|--|.....
+        // b ...|----------------|...
+        // = ...|-|=====|--|==|-|...
+        let mut segments = vec![];
+        if span.is_empty() {
+            return segments;
+        }
+        let mut position = span.start;
+        let first = self.entries.partition_point(|(s, _)| s.end <= span.start);
+        for (s, v) in self.entries[first..].iter().take_while(|(s, _)| s.start < span.end) {
+            if position < s.start {
+                segments.push((Span { start: position, end: s.start }, None));
+            }
+            let covered = Span { start: max(position, s.start), end: min(span.end, s.end) };
+            position = covered.end;
+            segments.push((covered, Some(v)));
+        }
+        if position < span.end {
+            segments.push((Span { start: position, end: span.end }, None));
+        }
+        segments
+    }
+
+    /// Returns the values of a `Span` that aren't covered by any span
+    pub fn gaps(&self, span: &Span<T>) -> SpanSet<T> {
+        self.segments(span)
+            .into_iter()
+            .filter(|(_, v)| v.is_none())
+            .map(|(s, _)| s)
+            .collect()
+    }
+}
+impl<T: Integer> SpanMap<Offset<T>, T> {
+
+    /// Moves a value by the offset of the span covering it
+    /// Uncovered values stay where they are
+    pub fn map_value(&self, value: T) -> Option<T> {
+        match self.get(value) {
+            Some(offset) => offset.apply(value),
+            None => Some(value),
+        }
+    }
+
+    /// Moves each segment of a `Span` by the offset of the span covering it
+    /// Uncovered segments stay where they are
+    pub fn map_span(&self, span: &Span<T>) -> Option<SpanSet<T>> {
+        self.map_set(&SpanSet::from(span.clone()))
+    }
+
+    /// Moves each segment of a `SpanSet` by the offset of the span covering it
+    /// Uncovered segments stay where they are
+    pub fn map_set(&self, set: &SpanSet<T>) -> Option<SpanSet<T>> {
+        set.iter()
+            .flat_map(|span| self.segments(span))
+            .map(|(s, offset)| match offset {
+                Some(offset) => offset.apply_span(&s),
+                None => Some(s),
+            })
+            .collect()
+    }
+}
+impl<V: Clone, T: Integer> FromIterator<(Span<T>, V)> for SpanMap<V, T> {
+    /// Inserts the spans in order, so later spans override earlier ones
+    fn from_iter<I: IntoIterator<Item = (Span<T>, V)>>(entries: I) -> SpanMap<V, T> {
+        let mut map = SpanMap::new();
+        for (span, value) in entries {
+            map.insert(span, value);
+        }
+        map
+    }
+}
+
+/// Which value `SpanTree::get` returns when more than one span covers a point
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OverlapPolicy {
+    /// The value of the span added first
+    FirstWins,
+    /// The value of the span added last
+    LastWins,
+    /// Spans that overlap are refused when the tree is built
+    Reject,
+}
+
+/// Two spans that overlap in a `SpanTree` built with `OverlapPolicy::Reject`
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+#[error("spans {first} and {second} overlap")]
+pub struct OverlapError<T: Integer> {
+    pub first: Span<T>,
+    pub second: Span<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Entry<V, T> {
+    order: usize,
+    span: Span<T>,
+    value: V,
+}
+
+/// An index of `Span`s and their values, which can overlap,
+/// that finds the spans covering a point or overlapping a span in logarithmic time
+/// The spans are sorted by start and searched as a balanced tree,
+/// where each node keeps the largest end below it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpanTree<V, T = usize> {
+    entries: Vec<Entry<V, T>>,
+    max_ends: Vec<T>,
+    policy: OverlapPolicy,
+}
+impl<V, T: Integer> SpanTree<V, T> {
+
+    /// Builds the tree from spans in the order they were added
+    /// Empty spans cover nothing, so they are dropped
+    pub fn new<I: IntoIterator<Item = (Span<T>, V)>>(spans: I, policy: OverlapPolicy) -> Result<SpanTree<V, T>, OverlapError<T>> {
+        let mut entries:Vec<Entry<V, T>> = spans
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (span, _))| !span.is_empty())
+            .map(|(order, (span, value))| Entry { order, span, value })
+            .collect();
+        entries.sort_by_key(|e| (e.span.start, e.order));
+        if policy == OverlapPolicy::Reject {
+            // sorted by start, so any overlap is with the furthest reaching span before it
+            let mut furthest:Option<&Span<T>> = None;
+            for entry in entries.iter() {
+                match furthest {
+                    Some(span) if entry.span.start < span.end => {
+                        return Err(OverlapError { first: span.clone(), second: entry.span.clone() });
+                    }
+                    Some(span) if entry.span.end <= span.end => {}
+                    _ => furthest = Some(&entry.span),
+                }
+            }
+        }
+        let mut max_ends:Vec<T> = entries.iter().map(|e| e.span.end).collect();
+        build(&entries, &mut max_ends, 0, entries.len());
+        Ok(SpanTree { entries, max_ends, policy })
+    }
+
+    /// Returns the number of spans in the tree
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value for a point, chosen by the tree's `OverlapPolicy`
+    pub fn get(&self, value: T) -> Option<&V> {
+        let containing = self.containing(value);
+        match self.policy {
+            OverlapPolicy::LastWins => containing.last(),
+            OverlapPolicy::FirstWins | OverlapPolicy::Reject => containing.first(),
+        }.map(|(_, v)| *v)
+    }
+
+    /// Returns every span covering a point and its value, in the order they were added
+    pub fn containing(&self, value: T) -> Vec<(&Span<T>, &V)> {
+        self.find(value, value)
+    }
+
+    /// Returns every span sharing a value with a `Span`, in the order they were added
+    pub fn overlapping(&self, span: &Span<T>) -> Vec<(&Span<T>, &V)> {
+        match span.last() {
+            Some(last) => self.find(span.start, last),
+            None => vec![],
+        }
+    }
+
+    /// Returns the entries that cover any of `first..=last`
+    fn find(&self, first: T, last: T) -> Vec<(&Span<T>, &V)> {
+        let mut found = vec![];
+        self.search(0, self.entries.len(), first, last, &mut found);
+        found.sort_by_key(|e| e.order);
+        found.into_iter().map(|e| (&e.span, &e.value)).collect()
+    }
+
+    fn search<'a>(&'a self, lo: usize, hi: usize, first: T, last: T, found: &mut Vec<&'a Entry<V, T>>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        // nothing below this node reaches the first value
+        if self.max_ends[mid] <= first {
+            return;
+        }
+        self.search(lo, mid, first, last, found);
+        let entry = &self.entries[mid];
+        // everything after this node starts after the last value
+        if entry.span.start <= last {
+            if first < entry.span.end {
+                found.push(entry);
+            }
+            self.search(mid + 1, hi, first, last, found);
+        }
+    }
+}
+
+/// Sets the largest end below each node of `lo..hi`, with the middle entry as its root
+fn build<V, T: Integer>(entries: &[Entry<V, T>], max_ends: &mut [T], lo: usize, hi: usize) -> Option<T> {
+    if lo >= hi {
+        return None;
+    }
+    let mid = lo + (hi - lo) / 2;
+    let left = build(entries, max_ends, lo, mid);
+    let right = build(entries, max_ends, mid + 1, hi);
+    let end = [left, right].into_iter().flatten().fold(entries[mid].span.end, max);
+    max_ends[mid] = end;
+    Some(end)
+}
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::collections::BTreeSet;
+    use proptest::prelude::*;
 
     #[test]
     fn test_span_new() {
@@ -122,6 +792,89 @@ mod test {
         assert_eq!(Span::new(5, 3), Span {start: 3, end: 5});
     }
 
+    #[test]
+    fn test_span_constructors() {
+        assert_eq!(Span::half_open(3, 5), Ok(Span {start: 3, end: 5}));
+        assert_eq!(Span::half_open(5, 3), Err(SpanError::Reversed {start: 5, end: 3}));
+        assert_eq!(Span::inclusive(3, 5), Ok(Span {start: 3, end: 6}));
+        assert_eq!(Span::inclusive(5, 5), Ok(Span {start: 5, end: 6}));
+        assert_eq!(Span::inclusive(5, 4), Err(SpanError::Reversed {start: 5, end: 4}));
+        assert_eq!(Span::inclusive(0u8, u8::MAX), Err(SpanError::Overflow {start: 0}));
+        assert_eq!(Span::from_len(-100i8, 200), Ok(Span {start: -100, end: 100}));
+        assert_eq!(Span::from_len(-100i8, 228), Err(SpanError::Overflow {start: -100}));
+        assert_eq!(Span::unbounded(7u16), Span {start: 7, end: u16::MAX});
+        assert!(Span::unbounded(7u16).is_unbounded() && !Span::new(7u16, 9).is_unbounded());
+        assert_eq!((Span::new(3, 6).last(), Span::new(3, 3).last()), (Some(5), None));
+    }
+
+    #[test]
+    fn test_span_parse() {
+        assert_eq!("3..5".parse(), Ok(Span {start: 3, end: 5}));
+        assert_eq!("3..=5".parse(), Ok(Span {start: 3, end: 6}));
+        assert_eq!("3-5".parse(), Ok(Span {start: 3, end: 6}));
+        assert_eq!(" 3 .. ".parse(), Ok(Span::unbounded(3)));
+        assert_eq!("79 14".parse(), Ok(Span {start: 79, end: 93}));
+        assert_eq!("-5--2".parse(), Ok(Span {start: -5, end: -1}));
+        assert_eq!("-5..-2".parse(), Ok(Span {start: -5, end: -2}));
+        assert_eq!("-5 3".parse(), Ok(Span {start: -5, end: -2}));
+        assert_eq!("5..3".parse::<Span>(), Err(SpanError::Reversed {start: 5, end: 3}));
+        assert_eq!("5-3".parse::<Span>(), Err(SpanError::Reversed {start: 5, end: 3}));
+        assert_eq!("250 10".parse::<Span<u8>>(), Err(SpanError::Overflow {start: 250}));
+        assert_eq!("5 -3".parse::<Span<i32>>(), Err(SpanError::Number("-3".to_string())));
+        assert_eq!("a..3".parse::<Span>(), Err(SpanError::Number("a".to_string())));
+        assert_eq!("5".parse::<Span>(), Err(SpanError::Format("5".to_string())));
+        assert_eq!("".parse::<Span>(), Err(SpanError::Format("".to_string())));
+        assert_eq!(SpanError::<usize>::Reversed {start: 5, end: 3}.to_string(), "span ends at 3 before it starts at 5");
+    }
+
+    #[test]
+    fn test_span_display() {
+        assert_eq!(Span::new(3, 5).to_string(), "3..5");
+        assert_eq!(Span::new(-5i8, -2).to_string(), "-5..-2");
+        assert_eq!(Span::unbounded(3u32).to_string(), "3..");
+        assert_eq!(Span::new(4, 4).to_string(), "4..4");
+    }
+
+    #[test]
+    fn test_span_max() {
+        // a span ending at the largest value is the unbounded span, and that value is never covered
+        let a: Span<u64> = Span::new(0, u64::MAX);
+        assert_eq!(a, Span::unbounded(0));
+        assert!(a.is_unbounded());
+        assert_eq!(a.to_string(), "0..");
+        assert!(a.contains_value(u64::MAX - 1) && !a.contains_value(u64::MAX));
+        assert_eq!(a.last(), Some(u64::MAX - 1));
+        assert_eq!(Span::inclusive(0, u64::MAX), Err(SpanError::Overflow {start: 0}));
+        assert_eq!(Span::from_len(1u64, u64::MAX), Err(SpanError::Overflow {start: 1}));
+        assert_eq!(RangeFrom::try_from(a.clone()), Ok(0..));
+        assert_eq!(RangeInclusive::try_from(a), Ok(0..=u64::MAX - 1));
+    }
+
+    #[test]
+    fn test_span_ranges() {
+        assert_eq!(Span::try_from(3..=5), Ok(Span {start: 3, end: 6}));
+        assert_eq!(Span::try_from(0..=u8::MAX), Err(SpanError::Overflow {start: 0}));
+        assert_eq!(RangeInclusive::try_from(Span::new(3, 6)), Ok(3..=5));
+        assert_eq!(RangeInclusive::try_from(Span::new(3, 3)), Err(SpanError::Empty {start: 3}));
+        assert_eq!(Span::from(3i64..), Span::unbounded(3));
+        assert_eq!(RangeFrom::try_from(Span::unbounded(3)), Ok(3..));
+        assert_eq!(RangeFrom::try_from(Span::new(3, 6)), Err(SpanError::Bounded {start: 3, end: 6}));
+    }
+
+    proptest! {
+        #[test]
+        fn test_span_display_parse(start in any::<i16>(), len in any::<u16>()) {
+            if let Ok(span) = Span::from_len(start, len) {
+                prop_assert_eq!(span.to_string().parse(), Ok(span.clone()));
+                if let Ok(range) = RangeInclusive::try_from(span.clone()) {
+                    prop_assert_eq!(format!("{}..={}", range.start(), range.end()).parse(), Ok(span.clone()));
+                    prop_assert_eq!(format!("{}-{}", range.start(), range.end()).parse(), Ok(span.clone()));
+                }
+                prop_assert_eq!(format!("{} {}", start, len).parse(), Ok(span));
+            }
+        }
+    }
+
     #[test]
     fn test_span_properties() {
         // a ......|--------|........................
@@ -238,5 +991,265 @@ mod test {
         assert_eq!(a.division(&g), Some(vec![Span { start:  7, end: 16 }]));
         assert_eq!(a.division(&h), Some(vec![Span { start:  7, end: 10 }, Span { start: 10, end: 16 }]));
     }
+
+    fn set(spans: &[(i32, i32)]) -> SpanSet<i32> {
+        spans.iter().map(|(a, b)| Span::new(*a, *b)).collect()
+    }
+
+    #[test]
+    fn test_span_set_normalized() {
+        // a ...|--|.|--|..........
+        // b .....|----|...|--|....
+        // = ...|=======|..|==|....
+        let a = set(&[(3, 6), (7, 10), (10, 12), (15, 18), (4, 11), (20, 20)]);
+        assert_eq!(a.spans(), &[Span::new(3, 12), Span::new(15, 18)]);
+        let mut b = SpanSet::new();
+        for span in [Span::new(15, 18), Span::new(7, 10), Span::new(3, 6), Span::new(4, 12), Span::new(20, 20)] {
+            b.insert(span);
+        }
+        assert_eq!(a, b);
+        b.insert(Span::new(12, 15));
+        assert_eq!(b.spans(), &[Span::new(3, 18)]);
+        assert_eq!(set(&[]), SpanSet::default());
+    }
+
+    #[test]
+    fn test_span_set_properties() {
+        let a = set(&[(-5, -2), (0, 3), (8, 9)]);
+        assert_eq!(a.len(), 7u32);
+        assert_eq!(a.min(), Some(-5));
+        assert_eq!(a.max(), Some(8));
+        assert!(a.contains_value(-5) && a.contains_value(2) && a.contains_value(8));
+        assert!(!a.contains_value(-2) && !a.contains_value(5) && !a.contains_value(9));
+        assert!(a.contains(&Span::new(0, 3)) && a.contains(&Span::new(4, 4)));
+        assert!(!a.contains(&Span::new(-3, 1)));
+        assert_eq!(a.iter().count(), 3);
+        assert_eq!((&a).into_iter().map(|s| s.start).collect::<Vec<_>>(), vec![-5, 0, 8]);
+        let empty:SpanSet<i32> = SpanSet::new();
+        assert_eq!((empty.len(), empty.min(), empty.max()), (0, None, None));
+        let full = SpanSet::from(Span::new(i8::MIN, i8::MAX));
+        assert_eq!((full.len(), full.max()), (255u8, Some(126)));
+    }
+
+    #[test]
+    fn test_span_set_algebra() {
+        // a ...|-----|...|-----|....
+        // b ......|-----|...|--|--|.
+        let a = set(&[(3, 9), (12, 18)]);
+        let b = set(&[(6, 12), (15, 18), (18, 21)]);
+        assert_eq!(a.union(&b), set(&[(3, 21)]));
+        assert_eq!(a.intersection(&b), set(&[(6, 9), (15, 18)]));
+        assert_eq!(a.difference(&b), set(&[(3, 6), (12, 15)]));
+        assert_eq!(b.difference(&a), set(&[(9, 12), (18, 21)]));
+        assert_eq!(a.symmetric_difference(&b), set(&[(3, 6), (9, 15), (18, 21)]));
+        assert_eq!(a.intersection(&SpanSet::new()), SpanSet::new());
+        assert_eq!(a.difference(&SpanSet::new()), a);
+        assert_eq!(a.difference(&set(&[(0, 30)])), SpanSet::new());
+        assert_eq!(set(&[(0, 10)]).difference(&set(&[(2, 3), (5, 6)])), set(&[(0, 2), (3, 5), (6, 10)]));
+    }
+
+    fn values(set: &SpanSet<i32>) -> BTreeSet<i32> {
+        set.iter().flat_map(|span| span.to_range()).collect()
+    }
+
+    fn span_set() -> impl Strategy<Value = SpanSet<i32>> {
+        prop::collection::vec((-20..20, 0..8), 0..6)
+            .prop_map(|spans| spans.into_iter().map(|(start, len)| Span::new(start, start + len)).collect())
+    }
+
+    proptest! {
+        #[test]
+        fn test_span_set_matches_values(a in span_set(), b in span_set()) {
+            let (x, y) = (values(&a), values(&b));
+            for result in [a.union(&b), a.intersection(&b), a.difference(&b), a.symmetric_difference(&b)] {
+                prop_assert!(result.spans().windows(2).all(|pair| pair[0].end < pair[1].start));
+                prop_assert!(result.iter().all(|span| !span.is_empty()));
+            }
+            prop_assert_eq!(values(&a.union(&b)), &x | &y);
+            prop_assert_eq!(values(&a.intersection(&b)), &x & &y);
+            prop_assert_eq!(values(&a.difference(&b)), &x - &y);
+            prop_assert_eq!(values(&a.symmetric_difference(&b)), &x ^ &y);
+            prop_assert_eq!(a.len() as usize, x.len());
+            prop_assert_eq!(a.max(), x.last().copied());
+        }
+    }
+
+    fn map(spans: &[(i32, i32, char)]) -> SpanMap<char, i32> {
+        spans.iter().map(|(a, b, v)| (Span::new(*a, *b), *v)).collect()
+    }
+
+    #[test]
+    fn test_span_map_insert() {
+        // a ...|-----|..|--|..|-----|...
+        // b ......|--------------|......
+        // = ...|a|b==============|a|....
+        let mut a = map(&[(3, 9), (12, 15), (18, 24)].map(|(x, y)| (x, y, 'a')));
+        a.insert(Span::new(6, 21), 'b');
+        assert_eq!(a.iter().map(|(s, v)| (s.to_range(), *v)).collect::<Vec<_>>(),
+                   vec![(3..6, 'a'), (6..21, 'b'), (21..24, 'a')]);
+        a.insert(Span::new(10, 12), 'c');
+        a.insert(Span::new(0, 0), 'd');
+        assert_eq!(a.iter().map(|(s, v)| (s.to_range(), *v)).collect::<Vec<_>>(),
+                   vec![(3..6, 'a'), (6..10, 'b'), (10..12, 'c'), (12..21, 'b'), (21..24, 'a')]);
+        assert_eq!((a.get(2), a.get(3), a.get(11), a.get(20), a.get(23), a.get(24)),
+                   (None, Some(&'a'), Some(&'c'), Some(&'b'), Some(&'a'), None));
+        assert_eq!(a.covered(), set(&[(3, 24)]));
+        assert!(SpanMap::<char, i32>::default().is_empty());
+    }
+
+    #[test]
+    fn test_span_map_segments() {
+        // a ......|-----|..|--|.....
+        // b ...|----------------|...
+        // = ...|-|=====|--|==|-|...
+        let a = map(&[(6, 12, 'a'), (14, 17, 'b')]);
+        assert_eq!(a.segments(&Span::new(3, 19)), vec![
+            (Span::new(3, 6), None),
+            (Span::new(6, 12), Some(&'a')),
+            (Span::new(12, 14), None),
+            (Span::new(14, 17), Some(&'b')),
+            (Span::new(17, 19), None),
+        ]);
+        assert_eq!(a.segments(&Span::new(8, 15)), vec![
+            (Span::new(8, 12), Some(&'a')),
+            (Span::new(12, 14), None),
+            (Span::new(14, 15), Some(&'b')),
+        ]);
+        assert_eq!(a.segments(&Span::new(9, 9)), vec![]);
+        assert_eq!(a.gaps(&Span::new(0, 20)), set(&[(0, 6), (12, 14), (17, 20)]));
+    }
+
+    #[test]
+    fn test_span_map_offsets() {
+        assert_eq!(Offset::new(5u8, 2), Offset::Down(3));
+        assert_eq!(Offset::new(-100i8, 100).apply(-100), Some(100));
+        assert_eq!(Offset::new(-100i8, 100).apply(-1), None);
+        assert_eq!(Offset::<u8>::Up(10).apply(250), None);
+        // the seed-to-soil table of day 5's example
+        let a:SpanMap<Offset<usize>> = [
+            (Span::new(98, 100), Offset::new(98, 50)),
+            (Span::new(50, 98), Offset::new(50, 52)),
+        ].into_iter().collect();
+        assert_eq!([79, 14, 55, 13, 98].map(|x| a.map_value(x)), [Some(81), Some(14), Some(57), Some(13), Some(50)]);
+        assert_eq!(a.map_span(&Span::new(45, 100)), Some([Span::new(45, 50), Span::new(50, 52), Span::new(52, 100)].into_iter().collect()));
+        assert_eq!(a.map_span(&Span::new(97, 99)).unwrap().spans(), &[Span::new(50, 51), Span::new(99, 100)]);
+        let seeds:SpanSet = [Span::new(79, 93), Span::new(55, 68)].into_iter().collect();
+        assert_eq!(a.map_set(&seeds).unwrap().spans(), &[Span::new(57, 70), Span::new(81, 95)]);
+        let b:SpanMap<Offset<u8>, u8> = [(Span::new(0, 10), Offset::Up(250))].into_iter().collect();
+        assert_eq!(b.map_span(&Span::new(0, 5)), Some(SpanSet::from(Span::new(250, 255))));
+        assert_eq!(b.map_span(&Span::new(0, 8)), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_span_map_matches_values(entries in prop::collection::vec((-20..20, 0..8, 0..4), 0..6), x in -25..30) {
+            // later spans override earlier ones, value by value
+            let mut values = std::collections::BTreeMap::new();
+            for (start, len, v) in entries.iter() {
+                for n in *start..start + len {
+                    values.insert(n, *v);
+                }
+            }
+            let a:SpanMap<i32, i32> = entries.iter().map(|(start, len, v)| (Span::new(*start, start + len), *v)).collect();
+            prop_assert!(a.iter().all(|(span, _)| !span.is_empty()));
+            prop_assert!(a.entries.windows(2).all(|pair| pair[0].0.end <= pair[1].0.start));
+            prop_assert_eq!(a.get(x), values.get(&x));
+            let segments = a.segments(&Span::new(-25, 30));
+            prop_assert!(segments.windows(2).all(|pair| pair[0].0.end == pair[1].0.start));
+            for (span, v) in segments {
+                prop_assert!(span.to_range().all(|n| values.get(&n) == v));
+            }
+        }
+    }
+
+    fn tree(spans: &[(i32, i32, char)], policy: OverlapPolicy) -> Result<SpanTree<char, i32>, OverlapError<i32>> {
+        SpanTree::new(spans.iter().map(|(a, b, v)| (Span::new(*a, *b), *v)), policy)
+    }
+
+    #[test]
+    fn test_span_tree_lookups() {
+        // a ...|--------|........
+        // b ......|--|...........
+        // c ........|-------|....
+        // d ..............|--|...
+        let spans = [(3, 12, 'a'), (6, 9, 'b'), (8, 17, 'c'), (14, 17, 'd'), (20, 20, 'e')];
+        let a = tree(&spans, OverlapPolicy::FirstWins).unwrap();
+        assert_eq!(a.len(), 4);
+        let values = |found: Vec<(&Span<i32>, &char)>| found.into_iter().map(|(_, v)| *v).collect::<String>();
+        assert_eq!(values(a.containing(8)), "abc");
+        assert_eq!(values(a.containing(12)), "c");
+        assert_eq!(values(a.containing(17)), "");
+        assert_eq!(values(a.containing(20)), "");
+        assert_eq!(values(a.overlapping(&Span::new(9, 14))), "ac");
+        assert_eq!(values(a.overlapping(&Span::new(9, 15))), "acd");
+        assert_eq!(values(a.overlapping(&Span::new(0, 3))), "");
+        assert_eq!(values(a.overlapping(&Span::new(8, 8))), "");
+        assert_eq!((a.get(8), a.get(13), a.get(2)), (Some(&'a'), Some(&'c'), None));
+        let b = tree(&spans, OverlapPolicy::LastWins).unwrap();
+        assert_eq!((b.get(8), b.get(5), b.get(15)), (Some(&'c'), Some(&'a'), Some(&'d')));
+        assert!(SpanTree::<char, i32>::new([], OverlapPolicy::Reject).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_span_tree_reject() {
+        assert_eq!(tree(&[(3, 12, 'a'), (14, 17, 'd'), (6, 9, 'b')], OverlapPolicy::Reject),
+                   Err(OverlapError { first: Span::new(3, 12), second: Span::new(6, 9) }));
+        assert_eq!(tree(&[(3, 12, 'a'), (4, 6, 'b'), (8, 17, 'c')], OverlapPolicy::Reject),
+                   Err(OverlapError { first: Span::new(3, 12), second: Span::new(4, 6) }));
+        let a = tree(&[(12, 15, 'b'), (3, 12, 'a'), (12, 12, 'c')], OverlapPolicy::Reject).unwrap();
+        assert_eq!((a.get(11), a.get(12)), (Some(&'a'), Some(&'b')));
+        assert_eq!(OverlapError { first: Span::new(3, 12), second: Span::new(6, 9) }.to_string(), "spans 3..12 and 6..9 overlap");
+    }
+
+    proptest! {
+        #[test]
+        fn test_span_tree_matches_scan(entries in prop::collection::vec((-20..20, 0..8), 0..40), x in -25..30, y in 0..10) {
+            let spans:Vec<(i32, i32, usize)> = entries.iter().enumerate().map(|(i, (start, len))| (*start, start + len, i)).collect();
+            let scan = |covers: &dyn Fn(&Span<i32>) -> bool| spans
+                .iter()
+                .map(|(a, b, v)| (Span::new(*a, *b), *v))
+                .filter(|(span, _)| covers(span))
+                .map(|(_, v)| v)
+                .collect::<Vec<_>>();
+            let first = SpanTree::new(spans.iter().map(|(a, b, v)| (Span::new(*a, *b), *v)), OverlapPolicy::FirstWins).unwrap();
+            let last = SpanTree::new(spans.iter().map(|(a, b, v)| (Span::new(*a, *b), *v)), OverlapPolicy::LastWins).unwrap();
+            let containing = scan(&|span| span.contains_value(x));
+            prop_assert_eq!(first.containing(x).into_iter().map(|(_, v)| *v).collect::<Vec<_>>(), containing.clone());
+            prop_assert_eq!(first.get(x), containing.first());
+            prop_assert_eq!(last.get(x), containing.last());
+            let span = Span::new(x, x + y);
+            let overlapping = scan(&|s| s.intersection(&span).is_some_and(|s| !s.is_empty()));
+            prop_assert_eq!(first.overlapping(&span).into_iter().map(|(_, v)| *v).collect::<Vec<_>>(), overlapping);
+            let disjoint = (0..spans.len()).all(|i| (0..i).all(|j| {
+                let (a, b) = (Span::new(spans[i].0, spans[i].1), Span::new(spans[j].0, spans[j].1));
+                a.is_empty() || b.is_empty() || !a.intersects(&b)
+            }));
+            prop_assert_eq!(SpanTree::new(spans.iter().map(|(a, b, v)| (Span::new(*a, *b), *v)), OverlapPolicy::Reject).is_ok(), disjoint);
+        }
+    }
+
+    #[test]
+    fn test_span_signed() {
+        let a: Span<i64> = Span::new(3, -5);
+        assert_eq!(a, Span {start: -5, end: 3});
+        assert_eq!(a.len(), 8u64);
+        assert!(a.contains_value(-5) && !a.contains_value(3));
+        assert_eq!(a.intersection(&Span::new(0, 10)), Some(Span {start: 0, end: 3}));
+        assert_eq!(a.subtraction(&Span::new(-2, 0)), Some(vec![Span {start: -5, end: -2}, Span {start: 0, end: 3}]));
+        assert_eq!(a.to_range(), -5..3);
+    }
+
+    #[test]
+    fn test_span_overflow() {
+        let a: Span<i128> = Span::new(i128::MIN, i128::MAX);
+        assert_eq!(a.len(), u128::MAX);
+        assert_eq!(a.checked_len(), None);
+        let b: Span<i8> = Span::new(-100, 27);
+        assert_eq!(b.len(), 127u8);
+        assert_eq!(b.checked_len(), Some(127));
+        let c: Span<u64> = Span::new(0, u64::MAX);
+        assert_eq!(c.checked_len(), Some(u64::MAX));
+        assert_eq!(c.union(&Span::new(u64::MAX - 1, u64::MAX)), Some(c.clone()));
+    }
 }
 
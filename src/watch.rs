@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+use anyhow::{bail, Context, Result};
+use crate::answer::Answer;
+use crate::answers::{Answers, ANSWERS_FILE};
+use crate::report::Record;
+use crate::runner::{self, PuzzleId};
+use crate::solution::{Part, Puzzle};
+use crate::{example_files, input_dir, year_dir};
+
+/// Modification times of every watched file that exists
+pub type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Returns the files a day's results depend on: its inputs, its year's known answers and,
+/// if given, every file under `src`
+pub fn watched_files(year: u16, day: u8, src: Option<&Path>) -> Result<Vec<PathBuf>> {
+    let year_dir = year_dir(year)?;
+    let prefix = format!("day{:02}", day);
+    let mut files:Vec<PathBuf> = fs::read_dir(&year_dir)
+        .with_context(|| format!("unable to read {}", year_dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.starts_with(&prefix)))
+        .collect();
+    files.push(year_dir.join(ANSWERS_FILE));
+    if let Some(src) = src {
+        walk(src, &mut files)?;
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("unable to read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            walk(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Returns when each of the files was last modified
+/// Files that don't exist are left out, so removing one counts as a change
+pub fn snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+            Some((path.clone(), modified))
+        })
+        .collect()
+}
+
+/// Runs a day against its examples and its puzzle input, in this process
+pub fn run_day(puzzle: &dyn Puzzle, answers: &Answers) -> Vec<Record> {
+    let mut files = example_files(puzzle.year(), puzzle.day());
+    files.push(format!("day{:02}.txt", puzzle.day()));
+    files
+        .iter()
+        .flat_map(|file_name| match runner::run(puzzle, file_name, &Part::ALL) {
+            Ok(run) => Record::from_run(puzzle.year(), puzzle.day(), puzzle.title(), &run, answers),
+            Err(_) => Record::from_error(puzzle.year(), puzzle.day(), puzzle.title(), file_name, &Part::ALL),
+        })
+        .collect()
+}
+
+/// Runs a day against its examples and its puzzle input with `cargo run`,
+/// so that changes to its source are compiled in
+pub fn run_cargo(id: PuzzleId, answers: &Answers) -> Result<Vec<Record>> {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let cargo = env::var_os("CARGO").unwrap_or("cargo".into());
+    let mut records = vec![];
+    for source in [&["--example"][..], &[]] {
+        let output = Command::new(&cargo)
+            .arg("run").arg("--quiet").arg("--manifest-path").arg(&manifest).arg("--")
+            .arg("--input-dir").arg(input_dir()?)
+            .args(["run", &id.to_string(), "--format", "json"])
+            .args(source)
+            .output()
+            .context("unable to run cargo")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.trim().is_empty() && !output.status.success() {
+            bail!("cargo run failed\n{}", String::from_utf8_lossy(&output.stderr));
+        }
+        for line in stdout.lines() {
+            let mut record:Record = serde_json::from_str(line)
+                .with_context(|| format!("unexpected output from cargo run: {}", line))?;
+            let part = Part::try_from(record.part)?;
+            record.verification = answers.verify(record.day, part, &record.input, &record.answer);
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+/// How an answer compares with the previous run
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change {
+    /// There was no answer for the same part and input last time
+    New,
+    Same,
+    Changed { previous: Answer },
+}
+
+/// Pairs every record with how its answer changed since the previous run
+pub fn diff<'a>(previous: &[Record], current: &'a [Record]) -> Vec<(Change, &'a Record)> {
+    current
+        .iter()
+        .map(|record| {
+            let before = previous.iter().find(|r| r.part == record.part && r.input == record.input);
+            let change = match before {
+                None => Change::New,
+                Some(before) if before.answer == record.answer => Change::Same,
+                Some(before) => Change::Changed { previous: before.answer.clone() },
+            };
+            (change, record)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+    use crate::answers::Verification;
+    use crate::find_puzzle;
+
+    #[test]
+    fn test_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day02.txt");
+        let files = vec![path.clone()];
+        let empty = snapshot(&files);
+        assert!(empty.is_empty());
+
+        fs::write(&path, "Game 1: 2 red\n").unwrap();
+        let written = snapshot(&files);
+        assert_ne!(written, empty);
+        assert_eq!(snapshot(&files), written);
+
+        let file = fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(5)).unwrap();
+        assert_ne!(snapshot(&files), written);
+    }
+
+    #[test]
+    fn test_run_day() {
+        let records = run_day(find_puzzle(2023, 2).unwrap(), &Answers::load(2023).unwrap());
+        let example:Vec<&Record> = records.iter().filter(|r| r.input == "day02-example.txt").collect();
+        assert_eq!(example.len(), 2);
+        assert_eq!(example[0].answer, Answer::Unsigned(8));
+        assert_eq!(example[0].verification, Verification::Verified);
+        assert!(records.iter().any(|r| r.input == "day02.txt"));
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = run_day(find_puzzle(2023, 2).unwrap(), &Answers::default());
+        let mut current = previous.clone();
+        current[1].answer = Answer::Unsigned(1);
+        current.push(Record { input: "day02-example-2.txt".to_string(), ..current[0].clone() });
+        let changes:Vec<Change> = diff(&previous, &current).into_iter().map(|(change, _)| change).collect();
+        assert_eq!(changes[0], Change::Same);
+        assert_eq!(changes[1], Change::Changed { previous: Answer::Unsigned(2286) });
+        assert_eq!(changes.last(), Some(&Change::New));
+    }
+}
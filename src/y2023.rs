@@ -0,0 +1,41 @@
+use crate::solution::Puzzle;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day14;
+pub mod day15;
+
+pub const YEAR: u16 = 2023;
+
+/// Every implemented day, in order
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day14::Day14,
+    &day15::Day15,
+];
+
+/// `src/template.rs` built as a day of this year, so a template that doesn't compile fails the tests
+#[cfg(test)]
+#[path = "template.rs"]
+pub(crate) mod template;
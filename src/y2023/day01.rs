@@ -1,18 +1,23 @@
 use std::collections::HashMap;
 use anyhow::Result;
-use crate::read_input_lines;
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(String::from).collect())
+}
 
 pub fn prepare(file_name: &str) -> Result<Vec<String>> {
-    let input = read_input_lines(file_name);
-    Ok(input)
+    parse(&read_year_input(super::YEAR, file_name)?)
 }
 
 pub fn part_1(_input: &Vec<String>) -> Option<u32> {
     let mut total:u32 = 0;
     for line in _input.iter() {
         let digits:Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
-        let first = digits.first().unwrap();
-        let last = digits.last().unwrap();
+        let first = digits.first()?;
+        let last = digits.last()?;
         total += 10 * first + last
     }
     Some(total)
@@ -38,13 +43,33 @@ pub fn part_2(_input: &Vec<String>) -> Option<u32> {
                 digits[index] = *digit_map.get(code).unwrap();
             }
         }
-        let first = digits.iter().find(|&&n| n != 0).unwrap();
-        let last = digits.iter().rfind(|&&n| n != 0).unwrap();
+        let first = digits.iter().find(|&&n| n != 0)?;
+        let last = digits.iter().rfind(|&&n| n != 0)?;
         total += 10 * first + last;
     }
     Some(total)
 }
 
+pub struct Day01;
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -52,15 +77,13 @@ mod test {
 
     #[test]
     fn test_part_1() {
-        if let Ok(input) = prepare("day01-example-1.txt") {
-            assert_eq!(part_1(&input), Some(142))
-        }
+        let input = prepare("day01-example-1.txt").unwrap();
+        assert_eq!(part_1(&input), Some(142))
     }
 
     #[test]
     fn test_part_2() {
-        if let Ok(input) = prepare("day01-example-2.txt") {
-            assert_eq!(part_2(&input), Some(281))
-        }
+        let input = prepare("day01-example-2.txt").unwrap();
+        assert_eq!(part_2(&input), Some(281))
     }
 }
@@ -1,5 +1,7 @@
-use crate::read_input_lines;
-use anyhow::Result;
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use std::cmp;
 
 #[derive(Debug)]
@@ -24,17 +26,18 @@ impl Game {
         }
         out
     }
-    fn power(&self) -> usize {
+    fn power(&self) -> Option<usize> {
         let Draw{reds, greens, blues} = self.max_drawn();
-        (reds * greens * blues) as usize
+        reds.checked_mul(greens)?.checked_mul(blues)
     }
 }
 
-pub fn prepare(file_name: &str) -> Result<Vec<Game>> {
-    let input = read_input_lines(file_name);
+pub fn parse(input: &str) -> Result<Vec<Game>> {
+    let input:Vec<&str> = input.lines().collect();
     let mut output:Vec<Game> = Vec::new();
     for line in input.iter() {
-        let (game_number, suffix) = sscanf!(line, "Game {usize}: {str}").unwrap();
+        let (game_number, suffix) = sscanf!(line, "Game {usize}: {str}")
+            .map_err(|_| anyhow!("invalid game {:?}", line))?;
         let mut game = Game{id: game_number, draws: Vec::new()};
         for draw in suffix.split("; ") {
             let (mut reds, mut greens, mut blues) = (0, 0, 0);
@@ -50,23 +53,47 @@ pub fn prepare(file_name: &str) -> Result<Vec<Game>> {
     Ok(output)
 }
 
+pub fn prepare(file_name: &str) -> Result<Vec<Game>> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
 pub fn part_1(_input: &Vec<Game>) -> Option<usize> {
-    let mut qualified_games:Vec<usize> = Vec::new();
+    let mut total:usize = 0;
     for game in _input.iter() {
         let Draw{ reds, greens, blues } = game.max_drawn();
         if reds <= 12 && greens <= 13 && blues <= 14 {
-            qualified_games.push(game.id as usize);
+            total = total.checked_add(game.id)?;
         }
     }
-    Some(qualified_games.iter().sum())
+    Some(total)
 }
 
 pub fn part_2(_input: &Vec<Game>) -> Option<usize> {
-    let mut game_powers:Vec<usize> = Vec::new();
+    let mut total:usize = 0;
     for game in _input.iter() {
-        game_powers.push(game.power())
+        total = total.checked_add(game.power()?)?;
+    }
+    Some(total)
+}
+
+pub struct Day02;
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
     }
-    Some(game_powers.iter().sum())
 }
 
 #[cfg(test)]
@@ -76,22 +103,19 @@ mod test {
 
     #[test]
     fn test_prepare() {
-        if let Ok(input) = prepare("day02-example.txt") {
-            assert_eq!(input.len(), 5);
-        }
+        let input = prepare("day02-example.txt").unwrap();
+        assert_eq!(input.len(), 5);
     }
 
     #[test]
     fn test_part_1() {
-        if let Ok(input) = prepare("day02-example.txt") {
-            assert_eq!(part_1(&input), Some(8))
-        }
+        let input = prepare("day02-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(8))
     }
 
     #[test]
     fn test_part_2() {
-        if let Ok(input) = prepare("day02-example.txt") {
-            assert_eq!(part_2(&input), Some(2286))
-        }
+        let input = prepare("day02-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(2286))
     }
 }
\ No newline at end of file
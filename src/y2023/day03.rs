@@ -1,6 +1,8 @@
 use std::collections::HashMap;
-use crate::read_input_lines;
-use anyhow::Result;
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{Context, Result};
 
 #[derive(Debug)]
 pub struct Point {
@@ -22,17 +24,17 @@ pub struct Part {
     span: Span,
 }
 impl Part {
-    fn from_buffer(digit_buffer: &Vec<char>, x: isize, y: isize) -> Part {
+    fn from_buffer(digit_buffer: &[char], x: isize, y: isize) -> Result<Part> {
         let part_digits: String = digit_buffer.iter().collect();
-        Part {
-            number: part_digits.parse().unwrap(),
+        Ok(Part {
+            number: part_digits.parse().with_context(|| format!("invalid part number {}", part_digits))?,
             span: Span {
                 xa: x - (digit_buffer.len() as isize),
                 ya: y - 1,
                 xb: x + 1,
                 yb: y + 1,
             }
-        }
+        })
     }
 
     fn adjacent_to(&self, p:&Point) -> bool {
@@ -43,8 +45,11 @@ impl Part {
     }
 }
 
-pub fn prepare(file_name: &str) -> Result<(Vec<Part>, HashMap<char, Vec<Point>>)> {
-    let input = read_input_lines(file_name);
+/// Part numbers and the positions of each symbol on the schematic
+pub type Schematic = (Vec<Part>, HashMap<char, Vec<Point>>);
+
+pub fn parse(input: &str) -> Result<Schematic> {
+    let input:Vec<&str> = input.lines().collect();
     let mut digit_buffer:Vec<char> = Vec::new();
     let mut part_numbers:Vec<Part> = Vec::new();
     let mut symbol_map:HashMap<char, Vec<Point>> = HashMap::new();
@@ -55,29 +60,24 @@ pub fn prepare(file_name: &str) -> Result<(Vec<Part>, HashMap<char, Vec<Point>>)
         for (ix, ch) in line.chars().enumerate() {
             let x = ix.try_into().unwrap();
             match ch {
-                ch if ch.is_digit(10) => {
+                ch if ch.is_ascii_digit() => {
                     digit_buffer.push(ch);
                     buffer_end = x;
                 },
                 _ => {
-                    if digit_buffer.len() > 0 {
-                        let part = Part::from_buffer(&digit_buffer, buffer_end, y);
+                    if !digit_buffer.is_empty() {
+                        let part = Part::from_buffer(&digit_buffer, buffer_end, y)?;
                         digit_buffer.clear();
                         part_numbers.push(part);
                     }
                     if ch == '.' { continue; }
-                    if !symbol_map.contains_key(&ch) {
-                        symbol_map.insert(ch, Vec::new());
-                    }
-                    if let Some(points) = symbol_map.get_mut(&ch) {
-                        points.push(Point {x, y});
-                    }
+                    symbol_map.entry(ch).or_default().push(Point {x, y});
                 }
             }
         }
         // catch parts that are at the end of the line
-        if digit_buffer.len() > 0 {
-            let part = Part::from_buffer(&digit_buffer, buffer_end, y);
+        if !digit_buffer.is_empty() {
+            let part = Part::from_buffer(&digit_buffer, buffer_end, y)?;
             digit_buffer.clear();
             part_numbers.push(part);
         }
@@ -85,26 +85,30 @@ pub fn prepare(file_name: &str) -> Result<(Vec<Part>, HashMap<char, Vec<Point>>)
     Ok((part_numbers, symbol_map))
 }
 
-pub fn part_1(input: &(Vec<Part>, HashMap<char, Vec<Point>>)) -> Option<usize> {
+pub fn prepare(file_name: &str) -> Result<Schematic> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+pub fn part_1(input: &Schematic) -> Option<usize> {
     let (part_numbers, symbol_map) = input;
-    let mut valid_parts:Vec<usize> = Vec::new();
+    let mut total:usize = 0;
     'partloop: for part in part_numbers.iter() {
         for (_key, value) in symbol_map.iter() {
             for p in value.iter() {
                 if part.adjacent_to(p) {
-                    valid_parts.push(part.number);
+                    total = total.checked_add(part.number)?;
                     continue 'partloop;
                 }
             }
         }
     }
-    Some(valid_parts.iter().sum())
+    Some(total)
 }
 
-pub fn part_2(input: &(Vec<Part>, HashMap<char, Vec<Point>>)) -> Option<usize> {
+pub fn part_2(input: &Schematic) -> Option<usize> {
     let (part_numbers, symbol_map) = input;
     let mut gears:Vec<usize> = Vec::new();
-    let mut ratios:Vec<usize> = Vec::new();
+    let mut total:usize = 0;
     for (_key, value) in symbol_map.iter() {
         for p in value.iter() {
             for part in part_numbers.iter() {
@@ -113,13 +117,33 @@ pub fn part_2(input: &(Vec<Part>, HashMap<char, Vec<Point>>)) -> Option<usize> {
                 }
             }
             if gears.len() == 2 {
-                ratios.push(gears.iter().product())
+                total = total.checked_add(gears[0].checked_mul(gears[1])?)?;
             }
             gears.clear();
         }
     }
 
-    Some(ratios.iter().sum())
+    Some(total)
+}
+
+pub struct Day03;
+impl Solution for Day03 {
+    type Input = Schematic;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
 }
 
 #[cfg(test)]
@@ -129,22 +153,19 @@ mod test {
 
     #[test]
     fn test_prepare() {
-        if let Ok((part_numbers, symbol_map)) = prepare("day03-example.txt") {
-            assert_eq!(part_numbers.len(), 10);
-            assert_eq!(symbol_map.len(), 4);
-        }
+        let (part_numbers, symbol_map) = prepare("day03-example.txt").unwrap();
+        assert_eq!(part_numbers.len(), 10);
+        assert_eq!(symbol_map.len(), 4);
     }
     #[test]
     fn test_part_1() {
-        if let Ok(input) = prepare("day03-example.txt") {
-            assert_eq!(part_1(&input), Some(4361))
-        }
+        let input = prepare("day03-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(4361))
     }
 
     #[test]
     fn test_part_2() {
-        if let Ok(input) = prepare("day03-example.txt") {
-            assert_eq!(part_2(&input), Some(467835))
-        }
+        let input = prepare("day03-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(467835))
     }
 }
\ No newline at end of file
@@ -0,0 +1,125 @@
+
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{anyhow, Context, Result};
+use std::cmp::min;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Card {
+    winners: HashSet<usize>,
+    numbers_held: HashSet<usize>,
+}
+impl Card {
+    fn matches(&self) -> usize {
+        self.winners.intersection(&self.numbers_held).count()
+    }
+
+    fn score(&self) -> Option<usize> {
+        let count = self.matches() as u32;
+        if count > 0 {
+            2_usize.checked_pow(count - 1)
+        } else { Some(0) }
+    }
+}
+impl FromStr for Card {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Sample: "Card  1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+        let (_, values) = s.trim().split_once(":").ok_or_else(|| anyhow!("card has no ':' {:?}", s))?;
+        let (winner_string, number_string) = values.split_once("|").ok_or_else(|| anyhow!("card has no '|' {:?}", s))?;
+        let winners:HashSet<usize> = winner_string
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .with_context(|| format!("invalid winning number in card {:?}", s))?;
+        let numbers_held:HashSet<usize> = number_string
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .with_context(|| format!("invalid number held in card {:?}", s))?;
+        Ok(Card {
+            winners,
+            numbers_held,
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>> {
+    let input:Vec<&str> = input.lines().collect();
+    let mut cards:Vec<Card> = Vec::new();
+    for line in input.iter() {
+        cards.push(line.parse()?)
+    }
+    Ok(cards)
+}
+
+pub fn prepare(file_name: &str) -> Result<Vec<Card>> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+pub fn part_1(cards: &[Card]) -> Option<usize> {
+    cards.iter().try_fold(0_usize, |total, c| {
+        total.checked_add(c.score()?)
+    })
+}
+
+pub fn part_2(cards: &[Card]) -> Option<usize> {
+    let mut won_cards:Vec<usize> = vec![1; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let start = i + 1;
+        // cards won past the end of the table don't exist
+        let bound = min(i + card.matches(), cards.len() - 1);
+        for j in start..=bound {
+            won_cards[j] = won_cards[j].checked_add(won_cards[i])?;
+        }
+    }
+    won_cards.iter().try_fold(0_usize, |total, n| total.checked_add(*n))
+}
+
+pub struct Day04;
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("Card 1: 41 48 | 83 86".parse::<Card>().is_ok());
+        assert!("Card 1: 41 x8 | 83 86".parse::<Card>().is_err());
+        assert!("Card 1: 41 48 | 83 -6".parse::<Card>().is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = prepare("day04-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(13))
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = prepare("day04-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(30))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,297 @@
+use std::str::FromStr;
+use crate::read_year_input;
+use crate::metrics;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{bail, Context, Result};
+use crate::util::span::{Offset, Span, SpanMap, SpanSet};
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ConversionRange {
+    input:Span,
+    output:Span,
+}
+impl FromStr for ConversionRange {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values:Vec<usize> = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .with_context(|| format!("invalid number in conversion range {:?}", s))?;
+        let [output, input, length] = values[..] else {
+            bail!("conversion range needs 3 numbers {:?}", s);
+        };
+        let span = |start:usize| Span::from_len(start, length).with_context(|| format!("conversion range too long {:?}", s));
+        Ok(ConversionRange{
+            input:  span(input)?,
+            output: span(output)?,
+        })
+    }
+}
+impl ConversionRange {
+    fn offset(&self) -> Offset<usize> {
+        Offset::new(self.input.start, self.output.start)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ConversionTable {
+    id: String,
+    offsets:SpanMap<Offset<usize>>,
+}
+impl ConversionTable {
+    fn new(id:String, conversions:Vec<ConversionRange>) -> ConversionTable {
+        // when input ranges overlap the last one in the table wins
+        let offsets = conversions
+            .iter()
+            .map(|conversion| (conversion.input.clone(), conversion.offset()))
+            .collect();
+        ConversionTable { id, offsets }
+    }
+
+    fn convert(&self, value:usize) -> Option<usize> {
+        // Converts value within multiple input ranges into value in outputs
+        // Unmatched values are returned
+        //      x     y      z
+        //         |------|
+        //                |-----|
+        //      ↓     ↓      ↓
+        //      x     a      b
+        metrics::count("tables_applied", 1);
+        match self.offsets.get(value) {
+            Some(offset) => offset.apply(value),
+            None => Some(value),
+        }
+    }
+
+    fn convert_span(&self, values:&SpanSet) -> Option<SpanSet> {
+        // Converts ranges within multiple input ranges into ranges in outputs
+        // Unmatched range segments are returned
+        //      |---------------|
+        //         |------|
+        //                |--|
+        //      ↓  ↓      ↓  ↓  ↓
+        //      |--|======|~~|--|
+        metrics::count("tables_applied", 1);
+        self.offsets.map_set(values)
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Vec<usize>, Vec<ConversionTable>)> {
+    let input:Vec<&str> = input.lines().collect();
+
+    let mut seeds:Vec<usize> = vec![];
+    let mut tables:Vec<(String, Vec<ConversionRange>)> = vec![];
+    let mut current:(String, Vec<ConversionRange>) = ("".to_string(), vec![]);
+
+    for line in input.iter() {
+        // skip empty lines
+        if line.is_empty() { continue; }
+
+        // grab the list of seeds from the header
+        if line.starts_with("seeds:") {
+            let (_, seed_string) = line.split_once(":").unwrap();
+            seeds = seed_string
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .with_context(|| format!("invalid seed in {:?}", line))?;
+            // part 2 reads the seeds as pairs of start and length
+            if !seeds.len().is_multiple_of(2) {
+                bail!("seeds must come in pairs of start and length {:?}", line);
+            }
+            if let Some(pair) = seeds.chunks_exact(2).find(|pair| Span::from_len(pair[0], pair[1]).is_err()) {
+                bail!("seed range too long {:?}", pair);
+            }
+            continue;
+        }
+
+        // grab the conversion table headers to use as keys
+        // this starts a new conversion table
+        if line.contains(":") {
+            // save the current conversion table and start a new one
+            // don't bother with the empty one we had to initialize before
+            if !current.0.is_empty() {
+                tables.push(current);
+            }
+            let (header, _) = line.split_once(" ").with_context(|| format!("invalid table header {:?}", line))?;
+            current = (header.to_string(), vec![]);
+            continue;
+        }
+
+        // parse line values for the conversion tables
+        let conversion_range:ConversionRange = line.parse()?;
+        current.1.push(conversion_range);
+    }
+    // catch the last conversion table
+    // to close out the loop
+    tables.push(current);
+    let conversion_tables = tables
+        .into_iter()
+        .map(|(id, conversions)| ConversionTable::new(id, conversions))
+        .collect();
+
+    // using Vec for seeds and conversion tables
+    // because the tables are applied in the order
+    // they appear in the input file
+    Ok((seeds, conversion_tables))
+}
+
+pub fn prepare(file_name: &str) -> Result<(Vec<usize>, Vec<ConversionTable>)> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+pub fn part_1((seeds, conversion_tables):&(Vec<usize>, Vec<ConversionTable>)) -> Option<usize> {
+    let mut locations:Vec<usize> = vec![];
+    for seed in seeds.iter() {
+        locations.push(
+            conversion_tables
+                .iter()
+                .fold(*seed, |a, x| {
+                    x.convert(a).unwrap()
+                }));
+    }
+    locations.into_iter().min()
+}
+
+pub fn part_2((seeds, conversion_tables):&(Vec<usize>, Vec<ConversionTable>)) -> Option<usize> {
+    // collect the seed ranges into one set of ranges
+    // pass the set to each conversion table in turn to get a new set
+    // the lowest location is the start of the lowest range
+    let seeds:SpanSet = seeds
+        .chunks_exact(2)
+        .map(|seed_span| Span::from_len(seed_span[0], seed_span[1]).ok())
+        .collect::<Option<_>>()?;
+    let locations = conversion_tables
+        .iter()
+        .try_fold(seeds, |seeds, table| table.convert_span(&seeds))?;
+    locations.min()
+}
+
+pub struct Day05;
+impl Solution for Day05 {
+    type Input = (Vec<usize>, Vec<ConversionTable>);
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_prepare() {
+        let (seeds, conversion_tables) = prepare("day05-example.txt").unwrap();
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+        assert_eq!(conversion_tables[3], ConversionTable::new(
+            "water-to-light".to_string(),
+            vec![
+                ConversionRange{input: Span::new(18, 25), output: Span::new(88, 95)},
+                ConversionRange{input: Span::new(25, 95), output: Span::new(18, 88)},
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("50 98 2".parse::<ConversionRange>().is_ok());
+        assert!("50 x 98 2".parse::<ConversionRange>().is_err());
+        assert!("50 98".parse::<ConversionRange>().is_err());
+        assert!(parse("seeds: 79 1x 55 13\n").is_err());
+        assert!(parse("seeds: 79 14 55\n").is_err());
+        assert!(parse("seeds: 18446744073709551615 2\n").is_err());
+    }
+
+    #[test]
+    fn text_convert() {
+        let seed_to_soil = ConversionTable::new(
+            "seed-to-soil".to_string(),
+            vec![
+                ConversionRange{input: Span::new(98, 100), output: Span::new(50, 52)},
+                ConversionRange{input: Span::new(50, 98), output: Span::new(52, 100)},
+            ]
+        );
+        assert_eq!(seed_to_soil.convert(98), Some(50));
+        assert_eq!(seed_to_soil.convert(53), Some(55));
+
+        let fertilizer_to_water = ConversionTable::new(
+            "fertilizer-to-water".to_string(),
+            vec![
+                ConversionRange{input: Span::new(53, 61), output: Span::new(49, 57)},
+                ConversionRange{input: Span::new(11, 53), output: Span::new(0, 42)},
+                ConversionRange{input: Span::new(0, 7),   output: Span::new(42, 49)},
+                ConversionRange{input: Span::new(7, 11),  output: Span::new(57, 61)},
+            ]
+        );
+        assert_eq!(fertilizer_to_water.convert(53), Some(49));
+
+        let overlapping = ConversionTable::new(
+            "overlapping".to_string(),
+            vec![
+                ConversionRange{input: Span::new(0, 10), output: Span::new(100, 110)},
+                ConversionRange{input: Span::new(5, 15), output: Span::new(200, 210)},
+            ]
+        );
+        assert_eq!(overlapping.convert(4), Some(104));
+        assert_eq!(overlapping.convert(5), Some(200));
+        assert_eq!(overlapping.convert(15), Some(15));
+    }
+
+    #[test]
+    fn test_convert_span() {
+        let (_, conversion_tables) = prepare("day05-example.txt").unwrap();
+        let humidity: SpanSet = [Span::new(46, 57), Span::new(78, 81)].into_iter().collect();
+        let humidity_to_location = &conversion_tables[6];
+        let locations = humidity_to_location.convert_span(&humidity).unwrap();
+        assert_eq!(locations.spans(),  &[Span::new(46, 56), Span::new(60, 61), Span::new(82, 85)]);
+    }
+
+    #[test]
+    fn test_table_metrics() {
+        let input = prepare("day05-example.txt").unwrap();
+        metrics::take();
+        part_1(&input);
+        assert_eq!(metrics::get("tables_applied"), (input.0.len() * input.1.len()) as u64);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = prepare("day05-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(35))
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = prepare("day05-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(46))
+    }
+
+    #[test]
+    fn test_part_1_puzzle() {
+        if let Ok(input) = prepare("day05.txt") {
+            assert_eq!(part_1(&input), Some(462648396))
+        }
+    }
+
+    #[test]
+    fn test_part_2_puzzle() {
+        if let Ok(input) = prepare("day05.txt") {
+            assert_eq!(part_2(&input), Some(2520479))
+        }
+    }
+}
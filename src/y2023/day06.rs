@@ -0,0 +1,135 @@
+
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct Race {
+    time:usize,
+    record:usize,
+}
+impl Race {
+    fn run(&self, pressed:usize) -> u128 {
+        if pressed >= self.time { 0 }
+        else {
+            pressed as u128 * (self.time - pressed) as u128
+        }
+    }
+
+    /// Counts the presses that beat the record
+    /// The distance rises to its peak at half the time and falls the same way after it,
+    /// so the wins are every press from the first winning one to its mirror image
+    fn count_wins(&self) -> usize {
+        let peak = self.time / 2;
+        if self.run(peak) <= self.record as u128 {
+            return 0;
+        }
+        // binary search for the first winning press before the peak
+        let (mut lo, mut hi) = (0, peak);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.run(mid) > self.record as u128 { hi = mid; } else { lo = mid + 1; }
+        }
+        self.time - 2 * lo + 1
+    }
+}
+
+/// Returns the text after the labels of the time and record lines
+fn values(input: &str) -> Result<(&str, &str)> {
+    let mut lines = input.lines().map(|line| line.split_once(":").map(|(_, values)| values));
+    match (lines.next().flatten(), lines.next().flatten()) {
+        (Some(times), Some(records)) => Ok((times, records)),
+        _ => Err(anyhow!("expected a line of times and a line of records")),
+    }
+}
+
+pub fn parse_1(input: &str) -> Result<Vec<Race>> {
+    let (times, records) = values(input)?;
+    let times:Vec<usize>   = times.split_whitespace().map(str::parse).collect::<Result<_, _>>().context("invalid time")?;
+    let records:Vec<usize> = records.split_whitespace().map(str::parse).collect::<Result<_, _>>().context("invalid record")?;
+    if times.len() != records.len() {
+        bail!("{} times but {} records", times.len(), records.len());
+    }
+    let races:Vec<Race>    = times.iter().zip(records.iter()).map(|(time, record)| Race {time:*time, record:*record}).collect();
+    Ok(races)
+}
+
+pub fn prepare_1(file_name: &str) -> Result<Vec<Race>> {
+    parse_1(&read_year_input(super::YEAR, file_name)?)
+}
+
+pub fn parse_2(input: &str) -> Result<Race> {
+    let (times, records) = values(input)?;
+    let time:usize   = times.split_whitespace().join("").parse().context("invalid time")?;
+    let record:usize = records.split_whitespace().join("").parse().context("invalid record")?;
+    Ok(Race{time, record})
+}
+
+pub fn prepare_2(file_name: &str) -> Result<Race> {
+    parse_2(&read_year_input(super::YEAR, file_name)?)
+}
+
+pub fn part_1(races: &[Race]) -> Option<usize> {
+    races.iter().try_fold(1_usize, |product, race| product.checked_mul(race.count_wins()))
+}
+
+pub fn part_2(race: &Race) -> Option<usize> {
+    Some(race.count_wins())
+}
+
+pub struct Day06;
+impl Solution for Day06 {
+    type Input = (Vec<Race>, Race);
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse_1(input)?, parse_2(input)?))
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(&input.0).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(&input.1).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_count_wins() {
+        assert_eq!(Race{time: 7, record: 9}.count_wins(), 4);
+        assert_eq!(Race{time: 30, record: 200}.count_wins(), 9);
+        assert_eq!(Race{time: 4, record: 4}.count_wins(), 0);
+        assert_eq!(Race{time: 0, record: 0}.count_wins(), 0);
+        assert_eq!(Race{time: 4_000_000_000, record: 0}.count_wins(), 3_999_999_999);
+        assert_eq!(Race{time: usize::MAX, record: usize::MAX}.count_wins(), usize::MAX - 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_1("Time: 7 15 30\nDistance: 9 40 200\n").is_ok());
+        assert!(parse_1("Time: 7 15 30\nDistance: 9 40\n").is_err());
+        assert!(parse_1("Time: 7 15\nDistance: 9 40 200\n").is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = prepare_1("day06-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(288))
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = prepare_2("day06-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(71503))
+    }
+}
\ No newline at end of file
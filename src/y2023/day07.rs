@@ -1,9 +1,11 @@
 use std::str::FromStr;
-use crate::read_input_lines;
-use anyhow::Result;
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Context, Result};
 use counter::Counter;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct Hand {
     code: String,
@@ -15,7 +17,10 @@ pub struct Hand {
 impl FromStr for Hand {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (hand_str, wager_str) = s.split_once(" ").unwrap();
+        let (hand_str, wager_str) = s.split_once(" ").ok_or_else(|| anyhow!("hand has no wager {:?}", s))?;
+        if hand_str.len() != 5 || !hand_str.chars().all(|c| "AKQJT98765432".contains(c)) {
+            bail!("hand must be 5 cards {:?}", hand_str);
+        }
 
         // the hand encoded as given, for identification
         let code:String = hand_str.to_string();
@@ -86,41 +91,62 @@ impl FromStr for Hand {
             card_priority.try_into().unwrap(),
         );
 
-        let wager = wager_str.parse().unwrap();
+        let wager = wager_str.parse().with_context(|| format!("invalid wager {:?}", wager_str))?;
 
         Ok(Hand{code, cards, priority, priority_wild, wager})
     }
 }
 
-pub fn prepare(file_name: &str) -> Result<Vec<Hand>> {
-    let input = read_input_lines(file_name);
+pub fn parse(input: &str) -> Result<Vec<Hand>> {
+    let input:Vec<&str> = input.lines().collect();
     let hands:Vec<Hand> = input
         .iter()
-        .filter_map(|line| line.parse().ok())
-        .collect();
+        .map(|line| line.parse())
+        .collect::<Result<_>>()?;
     Ok(hands)
 }
 
-pub fn part_1(hands: &mut Vec<Hand>) -> Option<usize> {
-    hands.sort_by(|a, b| a.priority.cmp(&b.priority));
-    let total = hands
+pub fn prepare(file_name: &str) -> Result<Vec<Hand>> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+/// Sums each wager times its rank, or None if the total doesn't fit
+fn winnings(hands: &[Hand]) -> Option<usize> {
+    hands
         .iter().enumerate()
-        .fold(0, |t, (k,v)| {
-            t + v.wager * (k + 1)
-        });
+        .try_fold(0_usize, |t, (k,v)| {
+            t.checked_add(v.wager.checked_mul(k + 1)?)
+        })
+}
 
-    Some(total)
+pub fn part_1(hands: &mut [Hand]) -> Option<usize> {
+    hands.sort_by_key(|a| a.priority);
+    winnings(hands)
 }
 
-pub fn part_2(hands: &mut Vec<Hand>) -> Option<usize> {
-    hands.sort_by(|a, b| a.priority_wild.cmp(&b.priority_wild));
-    let total = hands
-        .iter().enumerate()
-        .fold(0, |t, (k,v)| {
-            t + v.wager * (k + 1)
-        });
+pub fn part_2(hands: &mut [Hand]) -> Option<usize> {
+    hands.sort_by_key(|a| a.priority_wild);
+    winnings(hands)
+}
+
+pub struct Day07;
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(&mut input.clone()).into()
+    }
 
-    Some(total)
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(&mut input.clone()).into()
+    }
 }
 
 #[cfg(test)]
@@ -130,23 +156,22 @@ mod test {
 
     #[test]
     fn test_prepare() {
-        if let Ok(mut hands) = prepare("day07-example.txt") {
-            hands.sort_by(|a, b| a.priority.cmp(&b.priority));
-            assert_eq!(hands.len(), 5);
-            assert_eq!(hands[0].code, "32T3K");
-            assert_eq!(hands[1].code, "KTJJT");
-            assert_eq!(hands[2].code, "KK677");
-            assert_eq!(hands[3].code, "T55J5");
-            assert_eq!(hands[4].code, "QQQJA");
-
-            hands.sort_by(|a, b| a.priority_wild.cmp(&b.priority_wild));
-            assert_eq!(hands.len(), 5);
-            assert_eq!(hands[0].code, "32T3K");
-            assert_eq!(hands[1].code, "KK677");
-            assert_eq!(hands[2].code, "T55J5");
-            assert_eq!(hands[3].code, "QQQJA");
-            assert_eq!(hands[4].code, "KTJJT");
-        }
+        let mut hands = prepare("day07-example.txt").unwrap();
+        hands.sort_by_key(|a| a.priority);
+        assert_eq!(hands.len(), 5);
+        assert_eq!(hands[0].code, "32T3K");
+        assert_eq!(hands[1].code, "KTJJT");
+        assert_eq!(hands[2].code, "KK677");
+        assert_eq!(hands[3].code, "T55J5");
+        assert_eq!(hands[4].code, "QQQJA");
+
+        hands.sort_by_key(|a| a.priority_wild);
+        assert_eq!(hands.len(), 5);
+        assert_eq!(hands[0].code, "32T3K");
+        assert_eq!(hands[1].code, "KK677");
+        assert_eq!(hands[2].code, "T55J5");
+        assert_eq!(hands[3].code, "QQQJA");
+        assert_eq!(hands[4].code, "KTJJT");
     }
 
     #[test]
@@ -160,18 +185,22 @@ mod test {
         assert_eq!(hand2.priority_wild, ([2, 1, 1, 1, 0], [1, 14, 2, 3, 4]));
     }
 
+    #[test]
+    fn test_overflow() {
+        let mut hands = parse("32T3K 18446744073709551615\nKK677 2").unwrap();
+        assert_eq!(part_1(&mut hands), None);
+    }
+
     #[test]
     fn test_part_1() {
-        if let Ok(mut hands) = prepare("day07-example.txt") {
-            assert_eq!(part_1(&mut hands), Some(6440))
-        }
+        let mut hands = prepare("day07-example.txt").unwrap();
+        assert_eq!(part_1(&mut hands), Some(6440))
     }
 
     #[test]
     fn test_part_2() {
-        if let Ok(mut hands) = prepare("day07-example.txt") {
-            assert_eq!(part_2(&mut hands), Some(5905))
-        }
+        let mut hands = prepare("day07-example.txt").unwrap();
+        assert_eq!(part_2(&mut hands), Some(5905))
     }
 }
 
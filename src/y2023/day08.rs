@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::cmp::{max, min};
+use crate::read_year_input;
+use crate::metrics;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Node {
+    id:String,
+    left:String,
+    right:String,
+}
+impl FromStr for Node {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let shorter = s.replace(")", "");
+        let (id, tail) = shorter.split_once(" = (").ok_or_else(|| anyhow!("invalid node {:?}", s))?;
+        let (left, right) = tail.split_once(", ").ok_or_else(|| anyhow!("invalid node {:?}", s))?;
+        Ok(Node{
+            id: id.to_string(),
+            left: left.to_string(),
+            right: right.to_string(),
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Vec<char>, HashMap<String, Node>)> {
+    let input:Vec<&str> = input.lines().collect();
+    let cycle:Vec<char> = input
+        .first()
+        .ok_or_else(|| anyhow!("no instructions"))?
+        .trim()
+        .chars()
+        .collect();
+    if cycle.is_empty() || cycle.iter().any(|c| *c != 'L' && *c != 'R') {
+        bail!("instructions must be L or R {:?}", input[0]);
+    }
+    let nodes:HashMap<String, Node> = input
+        .iter().skip(2)
+        .map(|line| line.parse().map(|node:Node| (node.id.clone(), node)))
+        .collect::<Result<_>>()?;
+    Ok((cycle, nodes))
+}
+
+pub fn prepare(file_name: &str) -> Result<(Vec<char>, HashMap<String, Node>)> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+/// Returns None if the walk reaches a missing node, or never ends
+pub fn steps_from(instructions:&[char], nodes:&HashMap<String, Node>, start:String, end:&str) -> Option<usize> {
+    let repeat = instructions.len();
+    // after visiting every node at every instruction, the walk can only be going round in circles
+    let limit = repeat * (nodes.len() + 1);
+    let mut step:usize = 0;
+    let mut next = &start;
+    while let Some(node) = nodes.get(next) {
+        if step > limit {
+            return None;
+        }
+        let instruction = instructions[step % repeat];
+        next = match instruction {
+            'L' => &node.left,
+            'R' => &node.right,
+            _  => unreachable!(),
+        };
+        step += 1;
+        if next.ends_with(end) {
+            metrics::count("steps", step as u64);
+            return Some(step);
+        }
+    }
+    None
+}
+
+pub fn part_1((instructions, nodes):&(Vec<char>, HashMap<String, Node>)) -> Option<usize> {
+    steps_from(instructions, nodes, "AAA".to_string(), "ZZZ")
+}
+
+fn gcd(a:usize, b:usize) -> usize {
+    let mut m = max(a, b);
+    let mut n = min(a, b);
+    while m != 0 {
+        if m < n {
+            (m, n) = (n, m);
+        }
+        m %= n;
+    }
+    n
+}
+
+fn lcm(a:usize, b:usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+pub fn part_2((instructions, nodes):&(Vec<char>, HashMap<String, Node>)) -> Option<usize> {
+    let start_nodes:Vec<&Node> = nodes
+        .values()
+        .filter(|n| n.id.ends_with("A"))
+        .collect();
+    let paths:Vec<usize> = start_nodes
+        .iter()
+        .map(|n| steps_from(instructions, nodes, n.id.clone(), "Z"))
+        .collect::<Option<_>>()?;
+    let mut steps:usize = *paths.first()?;
+    for path in paths.iter().skip(1) {
+        steps = lcm(steps, *path);
+    }
+    Some(steps)
+}
+
+pub struct Day08;
+impl Solution for Day08 {
+    type Input = (Vec<char>, HashMap<String, Node>);
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_prepare() {
+        let (instructions, nodes) = prepare("day08-example-1.txt").unwrap();
+        assert_eq!(instructions, vec!['R', 'L']);
+        assert_eq!(nodes["AAA"],  Node { id: "AAA".to_string(), left: "BBB".to_string(), right: "CCC".to_string() });
+        let (instructions, nodes) = prepare("day08-example-2.txt").unwrap();
+        assert_eq!(instructions, vec!['L', 'L', 'R']);
+        assert_eq!(nodes["ZZZ"],  Node { id: "ZZZ".to_string(), left: "ZZZ".to_string(), right: "ZZZ".to_string() });
+    }
+
+    #[test]
+    fn test_part_1() {
+        let (instructions, nodes) = prepare("day08-example-1.txt").unwrap();
+        assert_eq!(part_1(&(instructions, nodes)), Some(2));
+        let (instructions, nodes) = prepare("day08-example-2.txt").unwrap();
+        assert_eq!(part_1(&(instructions, nodes)), Some(6))
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (instructions, nodes) = prepare("day08-example-3.txt").unwrap();
+        assert_eq!(part_2(&(instructions, nodes)), Some(6))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{Context, Result};
+use itertools::Itertools;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<isize>>> {
+    let input:Vec<&str> = input.lines().collect();
+    let output = input
+        .iter()
+        .map(|line| {
+            line.split(" ")
+                .map(|w| w.parse().with_context(|| format!("invalid value {:?}", w)))
+                .collect()
+        })
+        .collect::<Result<_>>()?;
+    Ok(output)
+}
+
+pub fn prepare(file_name: &str) -> Result<Vec<Vec<isize>>> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+/// Returns None if a difference or a sum doesn't fit in an isize
+pub fn extrapolate(input: &[Vec<isize>]) -> Option<(isize, isize)> {
+    let mut tails:Vec<isize> = vec![];
+    let mut heads:Vec<isize> = vec![];
+    let mut pyramid:Vec<Vec<isize>> = vec![];
+    for row in input.iter() {
+        let mut pyramid_row = row.clone();
+        while pyramid_row.iter().any(|n| *n != 0) {
+            pyramid.push(pyramid_row.clone());
+            pyramid_row = pyramid_row
+                .iter()
+                .tuple_windows()
+                .map(|(a, b)| b.checked_sub(*a))
+                .collect::<Option<_>>()?;
+        }
+        tails.push(
+            pyramid
+                .iter()
+                .try_fold(0_isize, |sum, row| sum.checked_add(*row.last().unwrap()))?
+        );
+        let mut firsts = pyramid
+            .iter()
+            .rev()
+            .map(|row| *row.first().unwrap());
+        heads.push(match firsts.next() {
+            Some(first) => firsts.try_fold(first, |a, b| b.checked_sub(a))?,
+            None => 0, // a row of zeros continues with zero
+        });
+        pyramid.clear();
+    }
+    let sum = |values:&[isize]| values.iter().try_fold(0_isize, |sum, n| sum.checked_add(*n));
+    Some((sum(&heads)?, sum(&tails)?))
+}
+
+pub fn part_1(input: &[Vec<isize>]) -> Option<isize> {
+    if let Some((_, tails)) = extrapolate(input) {
+        Some(tails)
+    } else { None }
+}
+
+pub fn part_2(input: &[Vec<isize>]) -> Option<isize> {
+    if let Some((heads, _)) = extrapolate(input) {
+        Some(heads)
+    } else { None }
+}
+
+pub struct Day09;
+impl Solution for Day09 {
+    type Input = Vec<Vec<isize>>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_overflow() {
+        let input = parse("9223372036854775807 -9223372036854775808").unwrap();
+        assert_eq!(extrapolate(&input), None);
+        let input = parse("9223372036854775807 9223372036854775807").unwrap();
+        assert_eq!(extrapolate(&input), Some((9223372036854775807, 9223372036854775807)));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = prepare("day09-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(114))
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = prepare("day09-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(2))
+    }
+}
\ No newline at end of file
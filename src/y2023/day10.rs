@@ -1,15 +1,20 @@
 use std::collections::HashMap;
-use crate::read_input_lines;
-use anyhow::Result;
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{bail, Result};
 use colored::Colorize;
 use itertools::Itertools;
 use std::cmp::{min, max};
 
-pub fn prepare(file_name: &str) -> Result<((isize, isize), HashMap<(isize, isize), Vec<(isize, isize)>>)> {
-    let input = read_input_lines(file_name);
+/// The start position and, for each position, the positions its pipe links to
+pub type PipeMap = ((isize, isize), HashMap<(isize, isize), Vec<(isize, isize)>>);
+
+pub fn parse(input: &str) -> Result<PipeMap> {
+    let input:Vec<&str> = input.lines().collect();
 
     // parse the content into a big ol' map
-    let mut start:(isize, isize) = (0,0);
+    let mut start:Option<(isize, isize)> = None;
     let mut pipe_segments: HashMap<(isize, isize), Vec<(isize, isize)>> = HashMap::new();
     for (m, line) in input.iter().enumerate() {
         for (n, c) in line.chars().enumerate() {
@@ -29,13 +34,20 @@ pub fn prepare(file_name: &str) -> Result<((isize, isize), HashMap<(isize, isize
                 _  => vec![],
             });
             if c == 'S' {
-                start = (x, y);
+                if start.is_some() {
+                    bail!("more than one start");
+                }
+                start = Some((x, y));
             }
         }
     }
 
+    let Some(start) = start else {
+        bail!("no start");
+    };
+
     // figure out who links to start
-    let start_links:Vec<(isize, isize)> = vec![
+    let start_links:Vec<(isize, isize)> = [
         (start.0, start.1 - 1),
         (start.0, start.1 + 1),
         (start.0 - 1, start.1),
@@ -47,46 +59,54 @@ pub fn prepare(file_name: &str) -> Result<((isize, isize), HashMap<(isize, isize
                 links.contains(&start)
             } else { false }
         })
-        .map(|loc| loc.clone())
+        .copied()
         .collect();
+    if start_links.len() != 2 {
+        bail!("start must link to 2 pipes, not {}", start_links.len());
+    }
     pipe_segments.insert(start, start_links);
 
     Ok((start, pipe_segments))
 }
 
+pub fn prepare(file_name: &str) -> Result<PipeMap> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
 pub fn find_route(start:&(isize, isize), pipe_segments:&HashMap<(isize, isize), Vec<(isize, isize)>>) -> Vec<(isize, isize)> {
-    let mut route:Vec<(isize, isize)> = vec![start.clone()];
-    let mut next = pipe_segments.get(&start).unwrap()[0];
+    let mut route:Vec<(isize, isize)> = vec![*start];
+    let mut next = pipe_segments.get(start).unwrap()[0];
     loop {
         route.push(next);
-        let links = pipe_segments.get(&next).unwrap();
+        // a pipe leading off the map ends the route
+        let Some(links) = pipe_segments.get(&next) else { break };
         let options:Vec<(isize, isize)> = links
             .iter()
             .filter(|link| !route.contains(link))
-            .map(|link| link.clone())
+            .copied()
             .collect();
-        if options.len() == 0 { break; }
+        if options.is_empty() { break; }
         next = options[0];
     }
     route
 }
 
-pub fn to_polygon(route:&Vec<(isize, isize)>) -> Vec<((isize, isize), (isize, isize))> {
-    let mut corners:Vec<(isize, isize)> = vec![route.first().unwrap().clone()];
+pub fn to_polygon(route:&[(isize, isize)]) -> Vec<((isize, isize), (isize, isize))> {
+    let mut corners:Vec<(isize, isize)> = vec![*route.first().unwrap()];
     let mut prev:&(isize, isize) = route.first().unwrap();
     let mut vertical = route[0].0 == route[1].0;
     for curr in route.iter().skip(1) {
         if vertical != (prev.0 == curr.0) {
             vertical = prev.0 == curr.0;
-            corners.push(prev.clone());
+            corners.push(*prev);
         }
         prev = curr;
     }
-    corners.push(route.last().unwrap().clone());
+    corners.push(*route.last().unwrap());
     corners
         .iter()
         .tuple_windows()
-        .map(|(a,b)| (a.clone(), b.clone()))
+        .map(|(a,b)| (*a, *b))
         .collect()
 }
 
@@ -105,21 +125,21 @@ pub fn ray_crosses(p:&(isize, isize), seg:&((isize, isize), (isize, isize)), ) -
 }
 
 
-pub fn part_1((start, pipe_segments): &((isize, isize), HashMap<(isize, isize), Vec<(isize, isize)>>)) -> Option<usize> {
+pub fn part_1((start, pipe_segments): &PipeMap) -> Option<usize> {
     let route = find_route(start, pipe_segments);
     Some(route.len() / 2)
 }
 
-pub fn part_2((start, pipe_segments): &((isize, isize), HashMap<(isize, isize), Vec<(isize, isize)>>)) -> Option<usize> {
+pub fn part_2((start, pipe_segments): &PipeMap) -> Option<usize> {
     let route = find_route(start, pipe_segments);
     let polygon = to_polygon(&route);
 
     let mut count:usize = 0;
     for (x, y) in pipe_segments.keys() {
-        if !route.contains(&(x.clone(), y.clone())) {
+        if !route.contains(&(*x, *y)) {
             let cross_count = polygon
                 .iter()
-                .filter(|seg| ray_crosses(&(x.clone(), y.clone()), seg))
+                .filter(|seg| ray_crosses(&(*x, *y), seg))
                 .count();
             if cross_count % 2 != 0 {
                 count += 1;
@@ -143,15 +163,14 @@ pub fn char_to_pipe(c:char) -> char {
     }
 }
 
-pub fn print_map(map:&Vec<String>, route:&Vec<(isize, isize)>) {
+pub fn render_map(map:&[String], route:&[(isize, isize)]) -> String {
 
-    let polygon = to_polygon(&route);
-    let polygon_points:Vec<(isize, isize)> = polygon.iter().map(|(a, _)| a.clone()).collect();
+    let polygon = to_polygon(route);
+    let polygon_points:Vec<(isize, isize)> = polygon.iter().map(|(a, _)| *a).collect();
 
-    print!("\n    0123456789abcedf");
-    print!("\n");
+    let mut output = String::from("\n    0123456789abcedf\n");
     for (m, line) in map.iter().enumerate() {
-        print!("{m:0>3} ");
+        output += &format!("{m:0>3} ");
         for (n, c) in line.chars().enumerate() {
             let x = n.try_into().unwrap();
             let y = m.try_into().unwrap();
@@ -160,46 +179,81 @@ pub fn print_map(map:&Vec<String>, route:&Vec<(isize, isize)>) {
                 .iter()
                 .filter(|seg| ray_crosses(&(x, y), seg))
                 .count();
-            if route.contains(&(x, y)) {
+            let cell = if route.contains(&(x, y)) {
                 if polygon_points.contains(&(x, y)) {
-                    print!("{}", p.to_string());
+                    p.to_string().normal()
                 } else {
-                    print!("{}", p.to_string().bright_green());
+                    p.to_string().bright_green()
                 }
+            } else if cross_count % 2 == 0 {
+                p.to_string().truecolor(96, 96, 96)
             } else {
-                if cross_count % 2 == 0 {
-                    print!("{}", p.to_string().truecolor(96, 96, 96));
-                } else {
-                    print!("{}", "■".to_string().bright_red());
-                }
-            }
+                "■".bright_red()
+            };
+            output += &cell.to_string();
         }
-        print!("\n");
+        output += "\n";
     }
+    output
+}
+
+pub fn print_map(map:&[String], route:&[(isize, isize)]) {
+    print!("{}", render_map(map, route));
+}
+
+/// Renders the map of an input with its route, for the dashboard
+pub fn visualize(input: &str) -> Result<String> {
+    let (start, pipe_segments) = parse(input)?;
+    let map:Vec<String> = input.lines().map(String::from).collect();
+    Ok(render_map(&map, &find_route(&start, &pipe_segments)))
 }
 
 
+pub struct Day10;
+impl Solution for Day10 {
+    type Input = PipeMap;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+    const VISUALIZE: Option<fn(&str) -> Result<String>> = Some(visualize);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use crate::y2023::YEAR;
+
+    fn read_input_lines(file_name: &str) -> Vec<String> {
+        read_year_input(YEAR, file_name).unwrap().lines().map(String::from).collect()
+    }
 
     #[test]
     fn test_map() {
-        for file_name in vec!["day10-example-2.txt", "day10-example-3.txt", "day10-example-4.txt"].iter() {
-            if let Ok((start, pipe_segments)) = prepare(file_name) {
-                let map   = read_input_lines(file_name);
-                let route = find_route(&start, &pipe_segments);
-                print_map(&map, &route);
-            }
+        for file_name in ["day10-example-2.txt", "day10-example-3.txt", "day10-example-4.txt"].iter() {
+            let (start, pipe_segments) = prepare(file_name).unwrap();
+            let map   = read_input_lines(file_name);
+            let route = find_route(&start, &pipe_segments);
+            print_map(&map, &route);
         }
     }
 
     #[test]
     fn test_part_1() {
-        if let Ok(input) = prepare("day10-example.txt") {
-            assert_eq!(part_1(&input), Some(8))
-        }
+        let input = prepare("day10-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(8))
     }
 
     #[test]
@@ -212,15 +266,12 @@ mod test {
 
     #[test]
     fn test_part_2() {
-        if let Ok(input) = prepare("day10-example-2.txt") {
-            assert_eq!(part_2(&input), Some(4))
-        }
-        if let Ok(input) = prepare("day10-example-3.txt") {
-            assert_eq!(part_2(&input), Some(8))
-        }
-        if let Ok(input) = prepare("day10-example-4.txt") {
-            assert_eq!(part_2(&input), Some(10))
-        }
+        let input = prepare("day10-example-2.txt").unwrap();
+        assert_eq!(part_2(&input), Some(4));
+        let input = prepare("day10-example-3.txt").unwrap();
+        assert_eq!(part_2(&input), Some(8));
+        let input = prepare("day10-example-4.txt").unwrap();
+        assert_eq!(part_2(&input), Some(10))
     }
     #[test]
     #[ignore]
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
-use crate::read_input_lines;
-use anyhow::Result;
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{bail, Result};
 use itertools::Itertools;
 
-pub fn prepare(file_name: &str) -> Result<HashMap<(isize,isize), (isize,isize)>> {
-    let input = read_input_lines(file_name);
+pub fn parse(input: &str) -> Result<HashMap<(isize,isize), (isize,isize)>> {
+    let input:Vec<&str> = input.lines().collect();
     let mut y_offset:isize = 0;
     let mut x_empty:Vec<isize> = Vec::new();
     let mut galaxies:HashMap<(isize,isize), (isize,isize)> = HashMap::new();
@@ -17,6 +19,8 @@ pub fn prepare(file_name: &str) -> Result<HashMap<(isize,isize), (isize,isize)>>
             // fill the x_empty vec with 1s
             // equal to the width of the map
             x_empty.resize(line.len(), 1);
+        } else if line.len() != x_empty.len() {
+            bail!("line {} is {} wide, not {}", y + 1, line.len(), x_empty.len());
         }
         let mut y_increment = 1;
         for (x, _) in line.match_indices("#") {
@@ -39,14 +43,18 @@ pub fn prepare(file_name: &str) -> Result<HashMap<(isize,isize), (isize,isize)>>
     let mut galaxy_map:HashMap<(isize,isize), (isize,isize)> = HashMap::new();
     for (galaxy, offsets) in galaxies.iter_mut() {
         galaxy_map.insert(
-            galaxy.clone(),
-            (x_offsets[galaxy.0 as usize], offsets.1.clone()),
+            *galaxy,
+            (x_offsets[galaxy.0 as usize], offsets.1),
         );
     }
 
     Ok(galaxy_map)
 }
 
+pub fn prepare(file_name: &str) -> Result<HashMap<(isize,isize), (isize,isize)>> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
 pub fn expand(galaxies: &HashMap<(isize,isize), (isize,isize)>, expand_by:isize) -> Result<Vec<(isize,isize)>> {
     // map the galaxy position and offset to
     // new positions based on expansion distance
@@ -60,7 +68,7 @@ pub fn expand(galaxies: &HashMap<(isize,isize), (isize,isize)>, expand_by:isize)
     Ok(galaxies_expanded)
 }
 
-pub fn count_distances(galaxies_expanded: &Vec<(isize,isize)>) -> isize {
+pub fn count_distances(galaxies_expanded: &[(isize,isize)]) -> isize {
     // total the cab distance for each combination
     // of expanded galaxy positions
     galaxies_expanded
@@ -82,16 +90,57 @@ pub fn part_2(galaxies: &HashMap<(isize,isize), (isize,isize)>) -> Option<isize>
     Some(distances)
 }
 
+pub struct Day11;
+impl Solution for Day11 {
+    type Input = HashMap<(isize,isize), (isize,isize)>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use proptest::prelude::*;
+
+    /// Images of galaxies and empty space, every line the same width
+    fn image() -> impl Strategy<Value = String> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(prop::bool::weighted(0.2), width), height)
+                .prop_map(|rows| rows
+                    .iter()
+                    .map(|row| row.iter().map(|galaxy| if *galaxy { '#' } else { '.' }).collect::<String>() + "\n")
+                    .collect())
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_expansion_monotonic(image in image(), smaller in 1..1_000isize, more in 0..1_000isize) {
+            let galaxies = parse(&image).unwrap();
+            let near = count_distances(&expand(&galaxies, smaller).unwrap());
+            let far = count_distances(&expand(&galaxies, smaller + more).unwrap());
+            prop_assert!(near <= far);
+        }
+    }
 
     #[test]
     fn test_part_1() {
-        if let Ok(input) = prepare("day11-example.txt") {
-            assert_eq!(part_1(&input), Some(374));
-        }
+        let input = prepare("day11-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(374));
     }
 
     #[test]
@@ -103,11 +152,10 @@ mod test {
 
     #[test]
     fn test_part_2() {
-        if let Ok(input) = prepare("day11-example.txt") {
-            // directly test smaller expansion distances
-            assert_eq!(count_distances(&expand(&input, 10).unwrap()), 1030);
-            assert_eq!(count_distances(&expand(&input, 100).unwrap()), 8410);
-        }
+        let input = prepare("day11-example.txt").unwrap();
+        // directly test smaller expansion distances
+        assert_eq!(count_distances(&expand(&input, 10).unwrap()), 1030);
+        assert_eq!(count_distances(&expand(&input, 100).unwrap()), 8410);
     }
 
     #[test]
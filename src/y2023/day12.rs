@@ -0,0 +1,272 @@
+use crate::read_year_input;
+use crate::metrics;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Context, Result};
+use cached::proc_macro::cached;
+
+#[derive(Debug)]
+pub struct Record {
+    pattern: String,
+    sequence: Vec<usize>,
+    needed: usize,
+}
+
+pub fn to_long(record:&Record) -> Record {
+    Record {
+        pattern:  vec![record.pattern.clone(); 5].join("?"),
+        sequence: vec![record.sequence.clone(); 5].concat(),
+        needed:   total(&vec![record.sequence.clone(); 5].concat()),
+    }
+}
+
+/// Sums the groups, saturating, as a total that big can never be filled anyway
+fn total(sequence:&[usize]) -> usize {
+    sequence.iter().fold(0, |total, n| total.saturating_add(*n))
+}
+
+pub fn total_permutations(record:&Record) -> usize {
+    lookup(record.pattern.clone(), record.needed, record.sequence.clone(), 0, 0, 0, vec![0])
+}
+
+// without the function caching macro
+// this would take ~100 hours :(
+// all arguments must be immutable and passed by value
+// for the caching to work
+#[cached]
+pub fn count_permutation(
+    pattern:String,
+    needed:usize,
+    required:Vec<usize>,
+    pos:usize,
+    slots_used:usize,
+    slots_filled:usize,
+    mut sequence:Vec<usize>) -> usize
+{
+    // only runs when the arguments are not cached yet
+    metrics::count("memo_misses", 1);
+
+    // if there are no more positions to check
+    // either we met requirements or not
+    if pos == pattern.len() {
+        // it's possible to end with a trailing 0 in the found sequence
+        // if so, truncate the sequence
+        if sequence.last().unwrap() == &0 { sequence.pop(); }
+        return if slots_filled == needed && required.starts_with(&sequence) { 1 } else { 0 }
+    };
+
+    // get the current working grouping
+    // pop the working value to make matching the sequences easier
+    let curr:usize = sequence.pop().unwrap();
+
+    // get the next token to match
+    let next:char = pattern.chars().nth(pos).unwrap();
+
+    match next {
+        '#' => {
+            // does the sequence so far match the required sequence?
+            // then keep going, otherwise, no valid options here
+            if required.starts_with(&sequence) {
+                // add to the current working grouping
+                sequence.push(curr + 1);
+                lookup(pattern.clone(), needed, required.clone(), pos + 1, slots_used, slots_filled + 1, sequence.clone())
+            } else { 0 }
+        },
+
+        '.' => {
+            // if the current working group is not empty
+            // stop adding to it, and start a new one
+            sequence.push(curr);
+            if curr > 0 {
+                sequence.push(0);
+            }
+            lookup(pattern.clone(), needed, required.clone(), pos + 1, slots_used, slots_filled, sequence)
+        },
+
+        '?' => {
+            // if a wild card, try both options
+            let octothorpe = {
+                let mut next_sequence = sequence.clone();
+                // does the sequence so far match the required sequence?
+                // then keep going, otherwise, no valid options here
+                if required.starts_with(&sequence) {
+                    // add to the current working grouping
+                    next_sequence.push(curr + 1);
+                    lookup(pattern.clone(), needed, required.clone(), pos + 1, slots_used, slots_filled + 1, next_sequence)
+                } else { 0 }
+            };
+            let dot = {
+                let mut next_sequence = sequence.clone();
+                // if the current working group is not empty
+                // stop adding to it, and start a new one
+                next_sequence.push(curr);
+                if curr > 0 {
+                    next_sequence.push(0);
+                }
+                lookup(pattern.clone(), needed, required.clone(), pos + 1, slots_used, slots_filled, next_sequence)
+            };
+
+            octothorpe + dot
+        },
+
+        _ => unreachable!(),
+    }
+}
+
+/// Counts permutations through the cache, counting a memo hit when nothing had to be computed
+fn lookup(
+    pattern:String,
+    needed:usize,
+    required:Vec<usize>,
+    pos:usize,
+    slots_used:usize,
+    slots_filled:usize,
+    sequence:Vec<usize>) -> usize
+{
+    let misses = metrics::get("memo_misses");
+    let count = count_permutation(pattern, needed, required, pos, slots_used, slots_filled, sequence);
+    if metrics::get("memo_misses") == misses {
+        metrics::count("memo_hits", 1);
+    }
+    count
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>> {
+    let input:Vec<&str> = input.lines().collect();
+    let records:Vec<Record> = input
+        .iter()
+        .map(|line| {
+            let (pattern, sequence) = line.split_once(" ").ok_or_else(|| anyhow!("record has no sequence {:?}", line))?;
+            if !pattern.chars().all(|c| "#.?".contains(c)) {
+                bail!("invalid pattern {:?}", pattern);
+            }
+            let sequence:Vec<usize> = sequence
+                .split(",")
+                .map(|s| s.parse().with_context(|| format!("invalid sequence {:?}", sequence)))
+                .collect::<Result<_>>()?;
+            Ok(Record{
+                pattern:  pattern.to_string(),
+                needed:   total(&sequence),
+                sequence,
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(records)
+}
+
+pub fn prepare(file_name: &str) -> Result<Vec<Record>> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+pub fn part_1(input: &[Record]) -> Option<usize> {
+    let mut count = 0;
+    for record in input.iter() {
+        let valid = total_permutations(record);
+        count += valid;
+    }
+    Some(count)
+}
+
+pub fn part_2(input: &[Record]) -> Option<usize> {
+    let mut count = 0;
+    for record in input.iter() {
+        let valid = total_permutations(&to_long(record));
+        count += valid;
+    }
+    Some(count)
+}
+
+pub struct Day12;
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_part_1_bounded(pattern in "[#.?]{1,14}", sequence in prop::collection::vec(1..5usize, 1..5)) {
+            let unknown = pattern.chars().filter(|c| *c == '?').count();
+            let line = format!("{} {}", pattern, sequence.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(","));
+            let input = parse(&line).unwrap();
+            prop_assert!(part_1(&input).unwrap() <= 1 << unknown);
+        }
+    }
+
+    #[test]
+    fn test_large_groups() {
+        let input = parse("?#? 18446744073709551615,1").unwrap();
+        assert_eq!(part_1(&input), Some(0));
+        assert_eq!(part_2(&input), Some(0));
+    }
+
+    #[test]
+    fn test_part_1_parts() {
+        let input = prepare("day12-example.txt").unwrap();
+        assert_eq!(total_permutations(&input[0]),  1);
+        assert_eq!(total_permutations(&input[1]),  4);
+        assert_eq!(total_permutations(&input[2]),  1);
+        assert_eq!(total_permutations(&input[3]),  1);
+        assert_eq!(total_permutations(&input[4]),  4);
+        assert_eq!(total_permutations(&input[5]), 10);
+    }
+
+    #[test]
+    fn test_memo_metrics() {
+        // a record no other test uses, so the cache starts empty for it
+        let record = Record { pattern: "??#?.?#??".to_string(), sequence: vec![2, 3], needed: 5 };
+        metrics::take();
+        let count = total_permutations(&record);
+        let (hits, misses) = (metrics::get("memo_hits"), metrics::get("memo_misses"));
+        assert!(misses > 0);
+        // the whole record is cached now, so counting it again is a single hit
+        assert_eq!(total_permutations(&record), count);
+        assert_eq!(metrics::get("memo_misses"), misses);
+        assert_eq!(metrics::get("memo_hits"), hits + 1);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = prepare("day12-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(21))
+    }
+
+    #[test]
+    fn test_part_1_puzzle() {
+        if let Ok(input) = prepare("day12.txt") {
+            assert_eq!(part_1(&input), Some(7771))
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = prepare("day12-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(525152))
+    }
+
+    #[test]
+    fn test_part_2_puzzle() {
+        if let Ok(input) = prepare("day12.txt") {
+            assert_eq!(part_2(&input), Some(10_861_030_975_833))
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+use crate::read_year_input;
+use crate::metrics;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{bail, Result};
+use colored::Colorize;
+use tracing::debug;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>> {
+    let input:Vec<&str> = input.lines().collect();
+    let mut grid:Vec<Vec<char>> = vec![];
+    for (y, line) in input.iter().enumerate() {
+        if grid.is_empty() {
+            grid = vec![vec!['.'; input.len()]; line.chars().count()];
+        }
+        if line.chars().count() != grid.len() {
+            bail!("line {} is {} wide, not {}", y + 1, line.chars().count(), grid.len());
+        }
+        for (x, ch) in line.chars().enumerate() {
+            grid[x][y] = ch;
+        }
+    }
+    Ok(grid)
+}
+
+pub fn prepare(file_name: &str) -> Result<Vec<Vec<char>>> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+pub fn rotate_map(map:&[Vec<char>]) -> Vec<Vec<char>> {
+    let mut grid:Vec<Vec<char>> = vec![];
+    for (x, line) in map.iter().enumerate() {
+        if grid.is_empty() {
+            grid = vec![vec!['.'; map.len()]; line.len()];
+        }
+        for (y, ch) in line.iter().enumerate() {
+            grid[line.len() - (y + 1)][x] = *ch;
+        }
+    }
+    grid
+}
+
+pub fn settle_map(map:&[Vec<char>]) -> Vec<Vec<char>> {
+    let mut settled = map.to_vec();
+    for col in settled.iter_mut() {
+        col.split_mut(|ch| ch == &'#')
+            .for_each(|segment| {
+                segment.sort_by(|a, b| b.partial_cmp(a).unwrap())
+            });
+    }
+    settled
+}
+
+pub fn cycle_map(map:&[Vec<char>]) -> Vec<Vec<char>> {
+    let n = settle_map(map);
+    let w = settle_map(&rotate_map(&n));
+    let s = settle_map(&rotate_map(&w));
+    rotate_map(&settle_map(&rotate_map(&s)))
+}
+
+pub fn weigh_map(map:&[Vec<char>]) -> usize {
+    let mut count = 0;
+    for col in map.iter() {
+        for (i, ch) in col.iter().enumerate() {
+            if ch == &'O' {
+                count += col.len() - i;
+            }
+        }
+    }
+    count
+}
+
+pub fn render_map(map:&[Vec<char>]) -> String {
+    let mut output = String::new();
+    for col in map.iter() {
+        output += &format!("\n\t{}", " ".on_cyan());
+        for ch in col.iter() {
+            let cell = match ch {
+                'O' => "● ".bright_white().on_cyan(),
+                '#' => "■ ".black().on_cyan(),
+                 _  => "  ".on_cyan(),
+            };
+            output += &cell.to_string();
+        }
+    }
+    output += "\n\n";
+    output
+}
+
+pub fn print_map(map:&[Vec<char>]) {
+    print!("{}", render_map(map));
+}
+
+/// Renders the platform of an input, for the dashboard
+pub fn visualize(input: &str) -> Result<String> {
+    Ok(render_map(&parse(input)?))
+}
+
+pub fn part_1(input: &[Vec<char>]) -> Option<usize> {
+    let grid:Vec<Vec<char>> = settle_map(input);
+    let count = weigh_map(&grid);
+    Some(count)
+}
+
+pub fn part_2(input: &[Vec<char>]) -> Option<usize> {
+
+    let mut map = input.to_vec();
+    let mut map_set:HashSet<Vec<Vec<char>>> = HashSet::new();
+
+    map_set.insert(map.clone());
+
+    let mut count  = 0;
+    let mut start:Option<usize> = None;
+    let mut period = 0;
+
+    let cycles = 1_000_000_000;
+
+    // find the starting point and period
+    // at which the map state repeats
+    for i in 0..cycles {
+        map = cycle_map(&map);
+        count = weigh_map(&map);
+        if map_set.contains(&map) {
+            if let Some(start) = start {
+                period = i - start;
+                metrics::count("cycles_until_repetition", i as u64 + 1);
+                debug!(start, period, "map repeats");
+                break;
+            }
+            map_set.clear();
+            start = Some(i);
+        }
+        map_set.insert(map.clone());
+    }
+
+    // start again, looping only for the remainder
+    // of the loops needed to get to a billion
+    let Some(start) = start else {
+        return Some(count);
+    };
+    map = input.to_vec();
+    for _ in 0..(start + ((cycles - start) % period)) {
+        map = cycle_map(&map);
+        count = weigh_map(&map);
+    }
+
+    Some(count)
+}
+
+pub struct Day14;
+impl Solution for Day14 {
+    type Input = Vec<Vec<char>>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+    const VISUALIZE: Option<fn(&str) -> Result<String>> = Some(visualize);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    #[ignore]
+    fn test_prepare() {
+        let input = prepare("day14-example.txt").unwrap();
+        print_map(&input);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_rotate() {
+        let input = prepare("day14-example.txt").unwrap();
+        let mut map = input.to_vec();
+        print_map(&map);
+        for _ in 0..3 {
+            map = cycle_map(&map);
+            print_map(&map);
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn test_draw_map() {
+        if let Ok(input) = prepare("day14.txt") {
+            print_map(&input);
+        }
+    }
+
+    #[test]
+    fn test_cycle_metrics() {
+        let input = prepare("day14-example.txt").unwrap();
+        metrics::take();
+        part_2(&input);
+        assert_eq!(metrics::get("cycles_until_repetition"), 17);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = prepare("day14-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(136))
+    }
+
+    #[test]
+    fn test_part_1_puzzle() {
+        if let Ok(input) = prepare("day14.txt") {
+            assert_eq!(part_1(&input), Some(112046))
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = prepare("day14-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(64))
+    }
+
+    #[test]
+    fn test_part_2_puzzle() {
+        if let Ok(input) = prepare("day14.txt") {
+            assert_eq!(part_2(&input), Some(104619))
+        }
+    }
+}
\ No newline at end of file
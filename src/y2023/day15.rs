@@ -0,0 +1,169 @@
+use indexmap::IndexMap;
+use crate::read_year_input;
+use crate::answer::Answer;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Context, Result};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum LensOp {
+    Remove((usize, String)),
+    Insert((usize, String, usize)),
+}
+impl FromStr for LensOp {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((label, focal_length)) = s.split_once('=') {
+            let focal_length:usize = focal_length.parse().with_context(|| format!("invalid focal length {:?}", s))?;
+            if !(1..=9).contains(&focal_length) {
+                bail!("focal length must be 1 to 9 {:?}", s);
+            }
+            Ok(LensOp::Insert((hash_seq(label), label.to_string(), focal_length)))
+        } else if let Some(label) = s.strip_suffix('-') {
+            Ok(LensOp::Remove((hash_seq(label), label.to_string())))
+        } else {
+            Err(anyhow!("step must insert or remove a lens {:?}", s))
+        }
+    }
+}
+
+/// A step of the initialization sequence, as written and as a lens operation
+#[derive(Debug)]
+pub struct Step {
+    seq: String,
+    op: LensOp,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step>> {
+    let output = input
+        .lines()
+        .next()
+        .ok_or_else(|| anyhow!("no initialization sequence"))?
+        .split(",")
+        .map(|seq| Ok(Step { seq: seq.to_string(), op: seq.parse()? }))
+        .collect::<Result<_>>()?;
+    Ok(output)
+}
+
+pub fn prepare(file_name: &str) -> Result<Vec<Step>> {
+    parse(&read_year_input(super::YEAR, file_name)?)
+}
+
+pub fn hash_seq(seq:&str) -> usize {
+    seq.chars().fold(0, |acc, ch| {
+        (((ch.to_ascii_lowercase() as usize) + acc) * 17) % 256
+    })
+}
+
+pub fn part_1(input: &[Step]) -> Option<usize> {
+    let output = input.iter().fold(0, |acc, step| acc + hash_seq(&step.seq));
+    Some(output)
+}
+
+pub fn part_2(input: &[Step]) -> Option<usize> {
+
+    // get thee a hashmap of hashmaps
+    // use IndexMap to preserve insertion order
+    let mut boxes:IndexMap<usize, IndexMap<String, usize>> = IndexMap::new();
+
+    // process the steps
+    for step in input.iter() {
+        match &step.op {
+            LensOp::Remove((id, label)) => {
+                if let Some(lenses) = boxes.get_mut(id) {
+                    lenses.shift_remove(label);
+                }
+            },
+            LensOp::Insert((id, label, focal_length)) => {
+                if let Some(lenses) = boxes.get_mut(id) {
+                    lenses.insert(label.clone(), *focal_length);
+                } else {
+                    boxes.insert(*id, IndexMap::from([(label.clone(), *focal_length)]));
+                }
+            }
+        }
+    }
+
+    let total:usize = boxes
+        .iter()
+        .fold(0, |total, (box_index, lenses)| {
+            total + lenses
+                .iter()
+                .enumerate()
+                .fold(0, |total, (slot_index, (_, focal_length))| {
+                    total + (box_index + 1) * (slot_index + 1) * focal_length
+                })
+        });
+
+    Some(total)
+}
+
+pub struct Day15;
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash_seq("rn=1"), 30);
+        assert_eq!(hash_seq("ab=5"), 197);
+        assert_eq!(hash_seq("qp-"),  14);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("rn=1,cm-,qp=3").is_ok());
+        assert!(parse("rn=1,cm,qp=3").is_err());
+        assert!(parse("rn=x").is_err());
+        assert!(parse("rn=18446744073709551615").is_err());
+        assert!(parse("rn=0").is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = prepare("day15-example.txt").unwrap();
+        assert_eq!(part_1(&input), Some(1320))
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part_1_puzzle() {
+        if let Ok(input) = prepare("day15.txt") {
+            assert_eq!(part_1(&input), Some(515210))
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = prepare("day15-example.txt").unwrap();
+        assert_eq!(part_2(&input), Some(145))
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part_2_puzzle() {
+        if let Ok(input) = prepare("day15.txt") {
+            assert_eq!(part_2(&input), Some(246762))
+        }
+    }
+}
\ No newline at end of file